@@ -0,0 +1,32 @@
+//! Measures the per-frame cost of many reactors waiting for a message that is rarely written.
+#![allow(missing_docs)]
+
+use bevy::app::{App, Startup};
+use bevy::prelude::{Commands, Message, TaskPoolPlugin, Update};
+use bevy_flurx::prelude::{wait, Reactor};
+use bevy_flurx::FlurxPlugin;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+#[derive(Message)]
+struct Rare;
+
+fn idle_reactors(c: &mut Criterion) {
+    let mut app = App::new();
+    app.add_plugins((TaskPoolPlugin::default(), FlurxPlugin))
+        .add_message::<Rare>()
+        .add_systems(Startup, |mut commands: Commands| {
+            for _ in 0..10000 {
+                commands.spawn(Reactor::schedule(|task| async move {
+                    task.will(Update, wait::message::comes::<Rare>()).await;
+                }));
+            }
+        });
+    app.update();
+
+    c.bench_function("10000 idle reactors", |b| {
+        b.iter(|| app.update());
+    });
+}
+
+criterion_group!(idle, idle_reactors);
+criterion_main!(idle);
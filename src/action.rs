@@ -25,12 +25,14 @@ use crate::prelude::ActionSeed;
 use crate::runner::{BoxedRunner, Output};
 pub use _tuple::tuple;
 use bevy::prelude::Reflect;
+pub use emit::Emit;
 pub use map::Map;
 pub use remake::Remake;
 
 #[path = "action/tuple.rs"]
 mod _tuple;
 pub mod delay;
+mod emit;
 pub mod inspect;
 mod map;
 pub mod omit;
@@ -0,0 +1,230 @@
+use crate::action::remake::Remake;
+use crate::prelude::CancellationHandlers;
+use crate::runner::{BoxedRunner, Output, Runner, RunnerIs};
+use bevy::prelude::{Entity, EntityEvent, Event, World};
+
+/// Triggers an [`Event`] created from the output of an [`Action`](crate::prelude::Action) or [`ActionSeed`](crate::prelude::ActionSeed).
+///
+/// The output is passed through, so it can be combined with [`Pipe`](crate::prelude::Pipe),
+/// [`Then`](crate::prelude::Then), and [`Map`](crate::prelude::Map).
+pub trait Emit<I1, O1, ActionOrSeed>: Sized
+where
+    O1: Clone + 'static,
+{
+    /// Triggers `E` created from the output when the action completes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(Event)]
+    /// struct DialogueFinished {
+    ///     choice: usize,
+    /// }
+    ///
+    /// impl From<usize> for DialogueFinished {
+    ///     fn from(choice: usize) -> Self {
+    ///         Self { choice }
+    ///     }
+    /// }
+    ///
+    /// App::new().add_observer(|finished: On<DialogueFinished>| {
+    ///     info!("choice: {}", finished.choice);
+    /// });
+    ///
+    /// Reactor::schedule(|task| async move {
+    ///     task.will(Update, once::run(|| 2_usize).emit::<DialogueFinished>()).await;
+    /// });
+    /// ```
+    fn emit<E>(self) -> ActionOrSeed
+    where
+        E: Event + From<O1>,
+        for<'a> E::Trigger<'a>: Default;
+
+    /// Triggers the [`EntityEvent`] `E` created from the reactor entity and the output when the action completes.
+    ///
+    /// The event is not triggered if the action isn't run by a reactor, since there is no entity to target.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(EntityEvent)]
+    /// struct DialogueFinished {
+    ///     entity: Entity,
+    ///     choice: usize,
+    /// }
+    ///
+    /// impl From<(Entity, usize)> for DialogueFinished {
+    ///     fn from((entity, choice): (Entity, usize)) -> Self {
+    ///         Self { entity, choice }
+    ///     }
+    /// }
+    ///
+    /// fn spawn_dialogue(mut commands: Commands) {
+    ///     commands
+    ///         .spawn(Reactor::schedule(|task| async move {
+    ///             task.will(Update, once::run(|| 2_usize).emit_on_reactor::<DialogueFinished>())
+    ///                 .await;
+    ///         }))
+    ///         .observe(|finished: On<DialogueFinished>| {
+    ///             info!("choice: {}", finished.choice);
+    ///         });
+    /// }
+    /// ```
+    fn emit_on_reactor<E>(self) -> ActionOrSeed
+    where
+        E: EntityEvent + From<(Entity, O1)>,
+        for<'a> E::Trigger<'a>: Default;
+}
+
+impl<I, O, A, Re> Emit<I, O, A> for Re
+where
+    I: 'static,
+    O: Clone + 'static,
+    Re: Remake<I, O, O, A> + 'static,
+{
+    #[inline]
+    fn emit<E>(self) -> A
+    where
+        E: Event + From<O>,
+        for<'a> E::Trigger<'a>: Default,
+    {
+        self.remake(|r1, o1, output| EmitRunner {
+            r1,
+            o1,
+            output,
+            emit: |world: &mut World, _: &CancellationHandlers, out: O| {
+                world.trigger(E::from(out));
+            },
+        })
+    }
+
+    #[inline]
+    fn emit_on_reactor<E>(self) -> A
+    where
+        E: EntityEvent + From<(Entity, O)>,
+        for<'a> E::Trigger<'a>: Default,
+    {
+        self.remake(|r1, o1, output| EmitRunner {
+            r1,
+            o1,
+            output,
+            emit: |world: &mut World, handlers: &CancellationHandlers, out: O| {
+                if let Some(waker) = handlers.waker() {
+                    world.trigger(E::from((waker.reactor(), out)));
+                }
+            },
+        })
+    }
+}
+
+struct EmitRunner<O> {
+    r1: BoxedRunner,
+    o1: Output<O>,
+    output: Output<O>,
+    emit: fn(&mut World, &CancellationHandlers, O),
+}
+
+impl<O> Runner for EmitRunner<O>
+where
+    O: Clone,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        match self.r1.run(world, token) {
+            RunnerIs::Completed => {
+                let o = self
+                    .o1
+                    .take()
+                    .expect("The output value has not been set!!!");
+                self.output.set(o.clone());
+                (self.emit)(world, token, o);
+                RunnerIs::Completed
+            }
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{Emit, Map, Pipe, Reactor, Then};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Entity, EntityEvent, Event, In, On, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Event)]
+    struct Finished(usize);
+
+    impl From<usize> for Finished {
+        fn from(num: usize) -> Self {
+            Self(num)
+        }
+    }
+
+    #[derive(EntityEvent)]
+    struct ReactorFinished {
+        entity: Entity,
+        num: usize,
+    }
+
+    impl From<(Entity, usize)> for ReactorFinished {
+        fn from((entity, num): (Entity, usize)) -> Self {
+            Self { entity, num }
+        }
+    }
+
+    #[test]
+    fn emit_output() {
+        let mut app = test_app();
+        app.add_observer(|finished: On<Finished>, mut count: ResMut<Count>| {
+            count.0 += finished.0;
+        });
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    once::run(|| 3_usize)
+                        .emit::<Finished>()
+                        .map(|num| num * 10)
+                        .emit::<Finished>()
+                        .pipe(once::run(|In(num): In<usize>, mut count: ResMut<Count>| {
+                            count.0 += num;
+                        })),
+                )
+                .await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(63));
+    }
+
+    #[test]
+    fn emit_on_reactor() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands
+                .spawn(Reactor::schedule(|task| async move {
+                    task.will(
+                        Update,
+                        once::run(|| {})
+                            .then(once::run(|| 5_usize))
+                            .emit_on_reactor::<ReactorFinished>(),
+                    )
+                    .await;
+                }))
+                .observe(|finished: On<ReactorFinished>, mut count: ResMut<Count>| {
+                    count.0 = finished.num;
+                });
+        });
+        app.update();
+        app.assert_resource_eq(Count(5));
+    }
+}
@@ -81,8 +81,6 @@ where
 {
     fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
         match self.r1.run(world, token) {
-            RunnerIs::Canceled => RunnerIs::Canceled,
-            RunnerIs::Running => RunnerIs::Running,
             RunnerIs::Completed => {
                 let o = self
                     .o1
@@ -92,6 +90,7 @@ where
                 self.output.set(map_fn(o));
                 RunnerIs::Completed
             }
+            other => other,
         }
     }
 }
@@ -136,12 +136,11 @@ struct OmitRunner {
 impl Runner for OmitRunner {
     fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
         match self.r1.run(world, token) {
-            RunnerIs::Canceled => RunnerIs::Canceled,
-            RunnerIs::Running => RunnerIs::Running,
             RunnerIs::Completed => {
                 self.output.set(());
                 RunnerIs::Completed
             }
+            other => other,
         }
     }
 }
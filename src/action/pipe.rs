@@ -90,9 +90,8 @@ where
     fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
         if !self.finished_r1 {
             match self.r1.run(world, token) {
-                RunnerIs::Canceled => return RunnerIs::Canceled,
-                RunnerIs::Running => return RunnerIs::Running,
                 RunnerIs::Completed => {}
+                other => return other,
             };
         }
 
@@ -110,7 +109,7 @@ where
 mod tests {
     use crate::action::{delay, once};
     use crate::prelude::{Map, Pipe, Reactor, Then, Through};
-    use crate::test_util::test;
+    use crate::testing;
     use crate::tests::{increment_count, test_app};
     use bevy::app::{AppExit, Startup};
     use bevy::prelude::*;
@@ -150,7 +149,7 @@ mod tests {
         let mut app = test_app();
         app.add_systems(Startup, |mut commands: Commands| {
             commands.spawn(Reactor::schedule(|task| async move {
-                task.will(Update, test::cancel().pipe(increment_count()))
+                task.will(Update, testing::cancel().pipe(increment_count()))
                     .await;
             }));
         });
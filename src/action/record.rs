@@ -2,26 +2,47 @@
 
 use crate::action::once;
 use crate::prelude::ActionSeed;
-pub use _push::push;
+use crate::runner::{BoxedRunner, CancellationHandlers, CancellationId, Runner, RunnerIs};
+pub use _push::{push, push_keep_redo};
 use bevy::prelude::*;
 use core::error::Error;
 use core::fmt::{Display, Formatter};
+use core::sync::atomic::{AtomicU64, Ordering};
+pub use global::GlobalRecord;
 pub use track::*;
 
 #[path = "record/push.rs"]
 mod _push;
 pub mod extension;
+mod global;
 pub mod redo;
+mod snapshot;
 mod track;
 pub mod undo;
 
 /// Clear the [`Record`].
 ///
-/// The output will be [`UndoRedoInProgress`] if an `undo` or `redo` is in progress.
-pub fn all_clear<M: 'static>() -> ActionSeed<(), Result<(), UndoRedoInProgress>> {
+/// The output will be [`EditRecordError::UndoRedoInProgress`] if an `undo` or `redo` is in progress.
+pub fn all_clear<M: 'static>() -> ActionSeed<(), EditRecordResult> {
     once::run(|mut store: NonSendMut<Record<M>>| store.all_clear())
 }
 
+/// Unlocks the [`Record`] and clears its poisoned state.
+///
+/// It does nothing if the record has not been registered.
+///
+/// See [`Record::force_reset`].
+pub fn force_reset<Act>() -> ActionSeed
+where
+    Act: Send + Sync + 'static,
+{
+    once::run(|record: Option<ResMut<Record<Act>>>| {
+        if let Some(mut record) = record {
+            record.force_reset();
+        }
+    })
+}
+
 /// Thrown when attempting to edit history while an `undo` or `redo` action is in progress.
 #[derive(Default, Debug, Eq, PartialEq, Copy, Clone, Hash, Ord, PartialOrd)]
 pub struct UndoRedoInProgress;
@@ -34,8 +55,43 @@ impl Display for UndoRedoInProgress {
 
 impl Error for UndoRedoInProgress {}
 
+/// The error returned when the history can't be edited.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+pub enum EditRecordError {
+    /// An `undo` or `redo` action is in progress.
+    UndoRedoInProgress,
+
+    /// A rollback panicked halfway, so the history may be inconsistent.
+    ///
+    /// The record can't be edited until [`Record::force_reset`] is called.
+    ///
+    /// The panic is detected only with the `std` feature, which is required to catch it.
+    /// Without it, a panicked rollback doesn't poison the record.
+    RecordPoisoned,
+}
+
+impl Display for EditRecordError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UndoRedoInProgress => UndoRedoInProgress.fmt(f),
+            Self::RecordPoisoned => {
+                f.write_str("failed edit history because a rollback panicked halfway")
+            }
+        }
+    }
+}
+
+impl Error for EditRecordError {}
+
+impl From<UndoRedoInProgress> for EditRecordError {
+    #[inline]
+    fn from(_: UndoRedoInProgress) -> Self {
+        Self::UndoRedoInProgress
+    }
+}
+
 /// Result type related to record edit operations.
-pub type EditRecordResult = Result<(), UndoRedoInProgress>;
+pub type EditRecordResult = Result<(), EditRecordError>;
 
 /// Manage the history of `undo` and `redo`.
 ///
@@ -45,6 +101,9 @@ pub struct Record<Act> {
     pub(crate) tracks: Vec<Track<Act>>,
     pub(crate) redo: Vec<(Track<Act>, ActionSeed)>,
     pub(crate) progressing: bool,
+    pub(crate) poisoned: bool,
+    /// The global push order of each track, used by [`GlobalRecord`].
+    stamps: Vec<u64>,
 }
 
 impl<Act> Record<Act>
@@ -52,26 +111,57 @@ where
     Act: 'static,
 {
     /// Clear all history of `undo` and `redo`.
-    pub fn all_clear(&mut self) -> Result<(), UndoRedoInProgress> {
-        self.err_if_progress()?;
+    pub fn all_clear(&mut self) -> EditRecordResult {
+        self.err_if_locked()?;
         self.tracks.clear();
+        self.stamps.clear();
         self.redo.clear();
         Ok(())
     }
 
     /// Returns true if it can be edited record.
     ///
-    /// Returns false if any `undo` or `redo` actions is in progress.
+    /// Returns false if any `undo` or `redo` actions is in progress,
+    /// or if the record is poisoned.
     #[inline]
     pub const fn can_edit(&self) -> bool {
-        !self.progressing
+        !self.progressing && !self.poisoned
+    }
+
+    /// Returns true if a rollback panicked halfway.
+    ///
+    /// A poisoned record can't be edited until [`Record::force_reset`] is called.
+    #[inline]
+    pub const fn is_poisoned(&self) -> bool {
+        self.poisoned
+    }
+
+    /// Unlocks the record and clears its poisoned state.
+    ///
+    /// The history is kept as it is, so call [`Record::all_clear`] afterward if it is no longer reliable.
+    /// This should be used only for recovering from a failed rollback;
+    /// calling it while an `undo` or `redo` is running allows the history to be edited concurrently.
+    pub fn force_reset(&mut self) {
+        self.progressing = false;
+        self.poisoned = false;
     }
 
     /// Push the `track`.
-    pub fn push(&mut self, track: Track<Act>) -> Result<(), UndoRedoInProgress> {
-        self.err_if_progress()?;
+    ///
+    /// The redo stack is cleared.
+    pub fn push(&mut self, track: Track<Act>) -> EditRecordResult {
+        self.push_keep_redo(track)?;
         self.redo.clear();
+        Ok(())
+    }
+
+    /// Push the `track` without clearing the redo stack.
+    ///
+    /// This is useful for non-destructive tracks such as selection changes.
+    pub fn push_keep_redo(&mut self, track: Track<Act>) -> EditRecordResult {
+        self.err_if_locked()?;
         self.tracks.push(track);
+        self.stamps.push(next_stamp());
         Ok(())
     }
 
@@ -87,9 +177,38 @@ where
         self.redo.iter().map(|(track, _)| &track.act)
     }
 
-    const fn err_if_progress(&self) -> Result<(), UndoRedoInProgress> {
-        if self.progressing {
-            Err(UndoRedoInProgress)
+    /// Returns the global push order of the last track which is not transient.
+    pub(crate) fn latest_stamp(&self) -> Option<u64> {
+        let last = self
+            .tracks
+            .iter()
+            .rposition(|track| !track.is_transient())?;
+        self.stamps.get(last).copied()
+    }
+
+    /// Removes the tracks after `index` and returns them.
+    ///
+    /// Transient tracks remain in the record unless `include_transient` is true.
+    pub(crate) fn take_tracks(&mut self, index: usize, include_transient: bool) -> Vec<Track<Act>> {
+        let index = index.min(self.tracks.len());
+        let stamps = self.stamps.split_off(index);
+        let mut taken = Vec::new();
+        for (track, stamp) in self.tracks.split_off(index).into_iter().zip(stamps) {
+            if !include_transient && track.is_transient() {
+                self.tracks.push(track);
+                self.stamps.push(stamp);
+            } else {
+                taken.push(track);
+            }
+        }
+        taken
+    }
+
+    pub(crate) const fn err_if_locked(&self) -> EditRecordResult {
+        if self.poisoned {
+            Err(EditRecordError::RecordPoisoned)
+        } else if self.progressing {
+            Err(EditRecordError::UndoRedoInProgress)
         } else {
             Ok(())
         }
@@ -102,10 +221,17 @@ impl<M> Default for Record<M> {
             tracks: Vec::new(),
             redo: Vec::new(),
             progressing: false,
+            poisoned: false,
+            stamps: Vec::new(),
         }
     }
 }
 
+fn next_stamp() -> u64 {
+    static STAMP: AtomicU64 = AtomicU64::new(0);
+    STAMP.fetch_add(1, Ordering::Relaxed)
+}
+
 impl<Op> Resource for Record<Op> where Op: Send + Sync + 'static {}
 
 /// # Safety: `Track::create_runner` must be called only on the main thread.
@@ -116,12 +242,9 @@ unsafe impl<Op> Sync for Record<Op> where Op: Send + Sync + 'static {}
 
 pub(crate) fn lock_record<Opr: Send + Sync + 'static>(world: &mut World) -> EditRecordResult {
     let mut record = world.get_resource_or_insert_with::<Record<Opr>>(Record::<Opr>::default);
-    if record.progressing {
-        Err(UndoRedoInProgress)
-    } else {
-        record.progressing = true;
-        Ok(())
-    }
+    record.err_if_locked()?;
+    record.progressing = true;
+    Ok(())
 }
 
 #[inline]
@@ -130,36 +253,72 @@ pub(crate) fn unlock_record<Opr: Send + Sync + 'static>(world: &mut World) {
     record.progressing = false;
 }
 
+/// Runs the runner of a rollback.
+///
+/// If it panics, `cleanup` is called instead of the cancellation handler and the record is poisoned
+/// before the panic is resumed, so the lock is released even if the panic is caught outside.
+#[cfg_attr(not(feature = "std"), allow(clippy::extra_unused_type_parameters))]
+pub(crate) fn run_rollback<Act: Send + Sync + 'static>(
+    runner: &mut BoxedRunner,
+    world: &mut World,
+    token: &mut CancellationHandlers,
+    cleanup: (Option<CancellationId>, fn(&mut World)),
+) -> RunnerIs {
+    #[cfg(feature = "std")]
+    {
+        use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+        match catch_unwind(AssertUnwindSafe(|| runner.run(world, token))) {
+            Ok(runner_is) => runner_is,
+            Err(panic) => {
+                let (cancellation_id, cleanup) = cleanup;
+                if let Some(id) = cancellation_id.as_ref() {
+                    token.unregister(id);
+                }
+                cleanup(world);
+                world
+                    .get_resource_or_insert_with::<Record<Act>>(Record::<Act>::default)
+                    .poisoned = true;
+                resume_unwind(panic)
+            }
+        }
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = cleanup;
+        runner.run(world, token)
+    }
+}
+
 fn push_tracks<Act: Send + Sync + 'static>(
     track: impl Iterator<Item = Track<Act>>,
     world: &mut World,
     in_undo: bool,
 ) -> EditRecordResult {
     let mut record = world.get_resource_or_insert_with::<Record<Act>>(Record::<Act>::default);
-    if in_undo && record.progressing {
-        return Err(UndoRedoInProgress);
+    if in_undo {
+        record.err_if_locked()?;
     }
     if in_undo {
         record.redo.clear();
     }
-    record.tracks.extend(track);
+    for track in track {
+        record.tracks.push(track);
+        record.stamps.push(next_stamp());
+    }
     Ok(())
 }
 
 fn push_track<Act: Send + Sync + 'static>(
     track: Track<Act>,
     world: &mut World,
-    in_undo: bool,
+    keep_redo: bool,
 ) -> EditRecordResult {
     let mut record = world.get_resource_or_insert_with::<Record<Act>>(Record::<Act>::default);
-    if in_undo && record.progressing {
-        return Err(UndoRedoInProgress);
-    }
-    if in_undo {
-        record.redo.clear();
+    if keep_redo {
+        record.push_keep_redo(track)
+    } else {
+        record.push(track)
     }
-    record.tracks.push(track);
-    Ok(())
 }
 
 #[cfg(test)]
@@ -1,14 +1,20 @@
 //! Allows undo and redo requests to be made using [`RequestUndo`] and [`RequestRedo`]
 //! from outside [`Reactor`].
+//!
+//! The outcome of each request is reported through [`RecordRequestCompleted`].
 
 use crate::action::record;
-use crate::prelude::{ActionSeed, Omit, Reactor, Record, Then};
+use crate::action::record::{EditRecordError, EditRecordResult};
+use crate::prelude::{once, wait, ActionSeed, GlobalRecord, OmitInput, Reactor, Record};
+use alloc::collections::VecDeque;
 use bevy::app::{App, PostUpdate, Update};
+use bevy::ecs::entity::Entities;
 use bevy::prelude::*;
 
 /// Represents a request `undo` operations.
 ///
-/// If an undo or redo is already in progress, the request will be ignored.
+/// How the request is handled while an undo or redo is in progress
+/// depends on the [`RecordRequestPolicy`].
 #[derive(Event, Message, Eq, PartialEq, Debug, Clone)]
 pub enum RequestUndo<Act> {
     /// [`record::undo::once`]
@@ -28,20 +34,21 @@ impl<Act> RequestUndo<Act>
 where
     Act: Clone + PartialEq + Send + Sync + 'static,
 {
-    fn to_action(&self) -> ActionSeed {
+    fn to_action(&self) -> ActionSeed<(), EditRecordResult> {
         match self {
-            RequestUndo::To(act) => record::undo::to().with(act.clone()).omit(),
-            RequestUndo::IndexTo(i) => record::undo::index_to::<Act>().with(*i).omit(),
-            RequestUndo::Once => record::undo::once::<Act>().omit(),
-            RequestUndo::All => record::undo::all::<Act>().omit(),
+            RequestUndo::To(act) => record::undo::to().with(act.clone()).omit_input(),
+            RequestUndo::IndexTo(i) => record::undo::index_to::<Act>().with(*i).omit_input(),
+            RequestUndo::Once => record::undo::once::<Act>(),
+            RequestUndo::All => record::undo::all::<Act>(),
         }
     }
 }
 
 /// Represents a request `redo` operations.
 ///
-/// If an undo or redo is already in progress, the request will be ignored.
-#[derive(Event, Message, Eq, PartialEq, Debug)]
+/// How the request is handled while an undo or redo is in progress
+/// depends on the [`RecordRequestPolicy`].
+#[derive(Event, Message, Eq, PartialEq, Debug, Clone)]
 pub enum RequestRedo<Act> {
     /// [`record::redo::once`]
     Once,
@@ -60,12 +67,99 @@ impl<Act> RequestRedo<Act>
 where
     Act: Clone + PartialEq + Send + Sync + 'static,
 {
-    fn to_action(&self) -> ActionSeed {
+    fn to_action(&self) -> ActionSeed<(), EditRecordResult> {
         match self {
-            RequestRedo::To(act) => record::redo::to().with(act.clone()).omit(),
-            RequestRedo::IndexTo(i) => record::redo::index_to::<Act>().with(*i).omit(),
-            RequestRedo::Once => record::redo::once::<Act>().omit(),
-            RequestRedo::All => record::redo::all::<Act>().omit(),
+            RequestRedo::To(act) => record::redo::to().with(act.clone()).omit_input(),
+            RequestRedo::IndexTo(i) => record::redo::index_to::<Act>().with(*i).omit_input(),
+            RequestRedo::Once => record::redo::once::<Act>(),
+            RequestRedo::All => record::redo::all::<Act>(),
+        }
+    }
+}
+
+/// Either [`RequestUndo`] or [`RequestRedo`].
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub enum RecordRequest<Act> {
+    /// The request made by [`RequestUndo`].
+    Undo(RequestUndo<Act>),
+
+    /// The request made by [`RequestRedo`].
+    Redo(RequestRedo<Act>),
+}
+
+impl<Act> RecordRequest<Act>
+where
+    Act: Clone + PartialEq + Send + Sync + 'static,
+{
+    fn to_action(&self) -> ActionSeed<(), EditRecordResult> {
+        match self {
+            RecordRequest::Undo(request) => request.to_action(),
+            RecordRequest::Redo(request) => request.to_action(),
+        }
+    }
+}
+
+/// The message written when a [`RequestUndo`] or [`RequestRedo`] has been processed.
+///
+/// If the request was dropped or replaced according to [`RecordRequestPolicy`],
+/// the result will be [`EditRecordError::UndoRedoInProgress`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct Act;
+///
+/// fn notify_failures(mut reader: MessageReader<RecordRequestCompleted<Act>>) {
+///     for completed in reader.read() {
+///         if let Err(e) = &completed.result {
+///             warn!("{e}");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Message, Eq, PartialEq, Debug, Clone)]
+pub struct RecordRequestCompleted<Act> {
+    /// The processed request.
+    pub request: RecordRequest<Act>,
+
+    /// The output of the `undo` or `redo` action.
+    pub result: EditRecordResult,
+}
+
+/// Decides how [`RequestUndo`] and [`RequestRedo`] are handled while another request is in progress.
+#[derive(Default, Debug, Eq, PartialEq, Copy, Clone, Hash)]
+pub enum RecordRequestPolicy {
+    /// Requests are queued and processed in order after the in-progress one has finished.
+    ///
+    /// Each request waits until the `undo` or `redo` started by other reactors has finished.
+    #[default]
+    Queue,
+
+    /// Requests are dropped if an undo or redo is in progress.
+    Drop,
+
+    /// Only the latest pending request is kept; any request waiting in the queue is replaced.
+    Replace,
+}
+
+/// Holds the pending [`RecordRequest`]s for `Act`.
+#[derive(Resource)]
+struct RecordRequests<Act> {
+    policy: RecordRequestPolicy,
+    queue: VecDeque<RecordRequest<Act>>,
+    reactor: Option<Entity>,
+}
+
+impl<Act> RecordRequests<Act> {
+    const fn new(policy: RecordRequestPolicy) -> Self {
+        Self {
+            policy,
+            queue: VecDeque::new(),
+            reactor: None,
         }
     }
 }
@@ -74,89 +168,223 @@ where
 /// from outside [`Reactor`].
 pub trait RecordExtension {
     /// Set up [`RequestUndo`] and [`RequestRedo`] and their associated systems.
+    ///
+    /// Requests made while an undo or redo is in progress are queued.
+    /// Use [`RecordExtension::add_record_with_policy`] to change this behavior.
+    ///
+    /// The record is also registered to [`GlobalRecord`].
     fn add_record<Act>(&mut self) -> &mut Self
     where
         Act: Clone + PartialEq + Send + Sync + 'static;
+
+    /// Set up [`RequestUndo`] and [`RequestRedo`] and their associated systems
+    /// with the specified [`RecordRequestPolicy`].
+    fn add_record_with_policy<Act>(&mut self, policy: RecordRequestPolicy) -> &mut Self
+    where
+        Act: Clone + PartialEq + Send + Sync + 'static;
 }
 
 impl RecordExtension for App {
+    #[inline]
     fn add_record<Act>(&mut self) -> &mut Self
     where
         Act: Clone + PartialEq + Send + Sync + 'static,
     {
+        self.add_record_with_policy::<Act>(RecordRequestPolicy::default())
+    }
+
+    fn add_record_with_policy<Act>(&mut self, policy: RecordRequestPolicy) -> &mut Self
+    where
+        Act: Clone + PartialEq + Send + Sync + 'static,
+    {
+        self.init_resource::<GlobalRecord>()
+            .world_mut()
+            .resource_mut::<GlobalRecord>()
+            .register::<Act>();
         self.init_resource::<Record<Act>>()
+            .insert_resource(RecordRequests::<Act>::new(policy))
             .add_message::<RequestUndo<Act>>()
             .add_message::<RequestRedo<Act>>()
+            .add_message::<RecordRequestCompleted<Act>>()
             .add_systems(
                 PostUpdate,
                 (
                     request_undo::<Act>.run_if(on_message::<RequestUndo<Act>>),
                     request_redo::<Act>.run_if(on_message::<RequestRedo<Act>>),
-                ),
+                    start_request_reactor::<Act>,
+                )
+                    .chain(),
             )
             .add_observer(apply_undo::<Act>)
             .add_observer(apply_redo::<Act>)
     }
 }
 
-fn request_undo<Act>(mut commands: Commands, mut er: MessageReader<RequestUndo<Act>>)
-where
+fn request_undo<Act>(
+    mut er: MessageReader<RequestUndo<Act>>,
+    mut requests: ResMut<RecordRequests<Act>>,
+    mut ew: MessageWriter<RecordRequestCompleted<Act>>,
+    record: Res<Record<Act>>,
+) where
     Act: Clone + Send + PartialEq + Sync + 'static,
 {
-    if let Some(actions) = er
-        .read()
-        .map(RequestUndo::<Act>::to_action)
-        .reduce(|r1, r2| r1.then(r2))
-    {
-        commands.spawn(Reactor::schedule(|task| async move {
-            task.will(Update, actions).await;
-        }));
+    for request in er.read() {
+        enqueue(
+            &mut requests,
+            &mut ew,
+            &record,
+            RecordRequest::Undo(request.clone()),
+        );
     }
 }
 
-fn request_redo<Act>(mut commands: Commands, mut er: MessageReader<RequestRedo<Act>>)
-where
+fn request_redo<Act>(
+    mut er: MessageReader<RequestRedo<Act>>,
+    mut requests: ResMut<RecordRequests<Act>>,
+    mut ew: MessageWriter<RecordRequestCompleted<Act>>,
+    record: Res<Record<Act>>,
+) where
     Act: Clone + Send + PartialEq + Sync + 'static,
 {
-    if let Some(actions) = er
-        .read()
-        .map(RequestRedo::<Act>::to_action)
-        .reduce(|r1, r2| r1.then(r2))
-    {
-        commands.spawn(Reactor::schedule(|task| async move {
-            task.will(Update, actions).await;
-        }));
+    for request in er.read() {
+        enqueue(
+            &mut requests,
+            &mut ew,
+            &record,
+            RecordRequest::Redo(request.clone()),
+        );
     }
 }
 
-fn apply_undo<Act>(trigger: On<RequestUndo<Act>>, mut commands: Commands)
-where
+fn apply_undo<Act>(
+    trigger: On<RequestUndo<Act>>,
+    mut commands: Commands,
+    mut requests: ResMut<RecordRequests<Act>>,
+    mut ew: MessageWriter<RecordRequestCompleted<Act>>,
+    record: Res<Record<Act>>,
+    entities: &Entities,
+) where
     Act: Clone + Send + PartialEq + Sync + 'static,
 {
-    let action = trigger.to_action();
-    commands.spawn(Reactor::schedule(move |task| async move {
-        task.will(Update, action).await;
-    }));
+    let request = RecordRequest::Undo(trigger.event().clone());
+    enqueue(&mut requests, &mut ew, &record, request);
+    start_request_reactor(commands.reborrow(), requests, entities);
 }
 
-fn apply_redo<Act>(trigger: On<RequestRedo<Act>>, mut commands: Commands)
-where
+fn apply_redo<Act>(
+    trigger: On<RequestRedo<Act>>,
+    mut commands: Commands,
+    mut requests: ResMut<RecordRequests<Act>>,
+    mut ew: MessageWriter<RecordRequestCompleted<Act>>,
+    record: Res<Record<Act>>,
+    entities: &Entities,
+) where
     Act: Clone + Send + PartialEq + Sync + 'static,
 {
-    let action = trigger.to_action();
-    commands.spawn(Reactor::schedule(move |task| async move {
-        task.will(Update, action).await;
-    }));
+    let request = RecordRequest::Redo(trigger.event().clone());
+    enqueue(&mut requests, &mut ew, &record, request);
+    start_request_reactor(commands.reborrow(), requests, entities);
+}
+
+fn enqueue<Act>(
+    requests: &mut RecordRequests<Act>,
+    ew: &mut MessageWriter<RecordRequestCompleted<Act>>,
+    record: &Record<Act>,
+    request: RecordRequest<Act>,
+) where
+    Act: Clone + Send + PartialEq + Sync + 'static,
+{
+    match requests.policy {
+        RecordRequestPolicy::Queue => {}
+        RecordRequestPolicy::Drop => {
+            let in_progress = !record.can_edit() || requests.reactor.is_some();
+            if in_progress || !requests.queue.is_empty() {
+                ew.write(RecordRequestCompleted {
+                    request,
+                    result: Err(EditRecordError::UndoRedoInProgress),
+                });
+                return;
+            }
+        }
+        RecordRequestPolicy::Replace => {
+            for replaced in requests.queue.drain(..) {
+                ew.write(RecordRequestCompleted {
+                    request: replaced,
+                    result: Err(EditRecordError::UndoRedoInProgress),
+                });
+            }
+        }
+    }
+    requests.queue.push_back(request);
+}
+
+fn start_request_reactor<Act>(
+    mut commands: Commands,
+    mut requests: ResMut<RecordRequests<Act>>,
+    entities: &Entities,
+) where
+    Act: Clone + Send + PartialEq + Sync + 'static,
+{
+    if requests.queue.is_empty() {
+        return;
+    }
+    // The reactor may have been despawned before processing all requests.
+    if requests
+        .reactor
+        .is_some_and(|entity| entities.contains(entity))
+    {
+        return;
+    }
+    let entity = commands
+        .spawn(Reactor::schedule(|task| async move {
+            while let Some(request) = task.will(Update, wait::output(pop_request::<Act>)).await {
+                let result = task.will(Update, request.to_action()).await;
+                task.will(
+                    Update,
+                    once::message::write().with(RecordRequestCompleted { request, result }),
+                )
+                .await;
+            }
+        }))
+        .id();
+    requests.reactor.replace(entity);
+}
+
+/// Pops the next request once the record can be edited.
+///
+/// Outputs `None` if there are no more requests.
+fn pop_request<Act>(
+    mut requests: ResMut<RecordRequests<Act>>,
+    record: Res<Record<Act>>,
+) -> Option<Option<RecordRequest<Act>>>
+where
+    Act: Send + Sync + 'static,
+{
+    if requests.queue.is_empty() {
+        requests.reactor = None;
+        return Some(None);
+    }
+    // A poisoned record isn't unlocked by waiting, so the request is started to report the error.
+    if !record.can_edit() && !record.is_poisoned() {
+        return None;
+    }
+    Some(requests.queue.pop_front())
 }
 
 //noinspection DuplicatedCode
 #[cfg(test)]
 mod tests {
     use crate::action::record::tests::push_undo_increment;
+    use crate::action::{delay, record};
     use crate::prelude::record::tests::push_num_act;
-    use crate::prelude::{Reactor, RequestRedo, RequestUndo, Then};
+    use crate::prelude::{
+        EditRecordError, EditRecordResult, Reactor, Record, RecordExtension, RecordRequest,
+        RecordRequestCompleted, RecordRequestPolicy, RequestRedo, RequestUndo, Rollback, Then,
+        Track,
+    };
     use crate::tests::{test_app, NumAct, TestAct};
-    use bevy::app::{Startup, Update};
+    use bevy::app::{App, Startup, Update};
+    use bevy::ecs::message::MessageCursor;
     use bevy::ecs::system::RunSystemOnce;
     use bevy::prelude::*;
     use bevy_test_helper::resource::count::Count;
@@ -396,4 +624,186 @@ mod tests {
         app.update();
         app.assert_resource_eq(Count(2));
     }
+
+    #[derive(Clone, PartialEq)]
+    struct DelayAct;
+
+    fn push_delay_tracks(app: &mut App, n: usize) {
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                for _ in 0..n {
+                    task.will(
+                        Update,
+                        record::push().with(Track {
+                            act: DelayAct,
+                            rollback: Rollback::undo(|| delay::frames().with(1)),
+                        }),
+                    )
+                    .await
+                    .unwrap();
+                }
+            }));
+        });
+    }
+
+    fn read_results<Act: Clone + Send + Sync + 'static>(
+        app: &mut App,
+        cursor: &mut MessageCursor<RecordRequestCompleted<Act>>,
+    ) -> Vec<EditRecordResult> {
+        let messages = app
+            .world()
+            .resource::<Messages<RecordRequestCompleted<Act>>>();
+        cursor.read(messages).map(|c| c.result).collect()
+    }
+
+    #[test]
+    fn queue_requests_from_trigger() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    push_undo_increment()
+                        .then(push_undo_increment())
+                        .then(push_undo_increment()),
+                )
+                .await
+                .unwrap();
+            }));
+        });
+        app.update();
+        for _ in 0..3 {
+            app.world_mut().trigger(RequestUndo::<TestAct>::Once);
+        }
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn queue_requests_while_undo_in_progress() {
+        let mut app = test_app();
+        app.add_record::<DelayAct>();
+        push_delay_tracks(&mut app, 2);
+        let mut cursor = MessageCursor::default();
+        app.update();
+        app.world_mut().trigger(RequestUndo::<DelayAct>::Once);
+        app.update();
+        app.world_mut().trigger(RequestUndo::<DelayAct>::Once);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(
+            read_results::<DelayAct>(&mut app, &mut cursor),
+            vec![Ok(()), Ok(())]
+        );
+        app.assert_resource(0, |record: &Record<DelayAct>| record.tracks.len());
+    }
+
+    #[test]
+    fn queue_requests_while_other_reactor_undoes() {
+        let mut app = test_app();
+        app.add_record::<DelayAct>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                for _ in 0..2 {
+                    task.will(
+                        Update,
+                        record::push().with(Track {
+                            act: DelayAct,
+                            rollback: Rollback::undo(|| delay::frames().with(3)),
+                        }),
+                    )
+                    .await
+                    .unwrap();
+                }
+                task.will(Update, record::undo::once::<DelayAct>())
+                    .await
+                    .unwrap();
+            }));
+        });
+        let mut cursor = MessageCursor::default();
+        for _ in 0..5 {
+            app.update();
+            if !app.world().resource::<Record<DelayAct>>().can_edit() {
+                break;
+            }
+        }
+        app.assert_resource(false, |record: &Record<DelayAct>| record.can_edit());
+        app.world_mut().trigger(RequestUndo::<DelayAct>::Once);
+        let mut results = Vec::new();
+        for _ in 0..10 {
+            app.update();
+            results.extend(read_results::<DelayAct>(&mut app, &mut cursor));
+        }
+        assert_eq!(results, vec![Ok(())]);
+        app.assert_resource(0, |record: &Record<DelayAct>| record.tracks.len());
+    }
+
+    #[test]
+    fn drop_requests_while_undo_in_progress() {
+        let mut app = test_app();
+        app.add_record_with_policy::<DelayAct>(RecordRequestPolicy::Drop);
+        push_delay_tracks(&mut app, 2);
+        let mut cursor = MessageCursor::default();
+        app.update();
+        app.world_mut().trigger(RequestUndo::<DelayAct>::Once);
+        app.update();
+        app.world_mut().trigger(RequestUndo::<DelayAct>::Once);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(
+            read_results::<DelayAct>(&mut app, &mut cursor),
+            vec![Err(EditRecordError::UndoRedoInProgress), Ok(())]
+        );
+        app.assert_resource(1, |record: &Record<DelayAct>| record.tracks.len());
+    }
+
+    #[test]
+    fn replace_pending_request() {
+        let mut app = test_app();
+        app.add_record_with_policy::<DelayAct>(RecordRequestPolicy::Replace);
+        push_delay_tracks(&mut app, 3);
+        let mut cursor = MessageCursor::default();
+        app.update();
+        app.world_mut().trigger(RequestUndo::<DelayAct>::Once);
+        app.update();
+        app.world_mut().trigger(RequestUndo::<DelayAct>::Once);
+        app.world_mut().trigger(RequestUndo::<DelayAct>::Once);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(
+            read_results::<DelayAct>(&mut app, &mut cursor),
+            vec![Err(EditRecordError::UndoRedoInProgress), Ok(()), Ok(())]
+        );
+        app.assert_resource(1, |record: &Record<DelayAct>| record.tracks.len());
+    }
+
+    #[test]
+    fn write_completed_message_from_message_request() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, push_undo_increment()).await.unwrap();
+            }));
+        });
+        app.add_systems(Startup, |mut ew: MessageWriter<RequestUndo<TestAct>>| {
+            ew.write(RequestUndo::Once);
+        });
+        let mut cursor = MessageCursor::default();
+        app.update();
+        app.update();
+        let messages = app
+            .world()
+            .resource::<Messages<RecordRequestCompleted<TestAct>>>();
+        let completed = cursor.read(messages).cloned().collect::<Vec<_>>();
+        assert_eq!(
+            completed,
+            vec![RecordRequestCompleted {
+                request: RecordRequest::Undo(RequestUndo::Once),
+                result: Ok(()),
+            }]
+        );
+    }
 }
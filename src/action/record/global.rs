@@ -0,0 +1,210 @@
+use crate::action::record::{EditRecordError, EditRecordResult, Record};
+use crate::prelude::ActionSeed;
+use bevy::prelude::*;
+use core::any::TypeId;
+
+pub(crate) type UndoFn = fn() -> ActionSeed<(), EditRecordResult>;
+
+/// Tracks the push order across multiple [`Record`]s.
+///
+/// Each `Act` type keeps its own [`Record`], but [`record::undo::global`](crate::prelude::record::undo::global)
+/// undoes whichever registered record holds the most recently pushed track.
+/// This makes it possible to implement a single undo shortcut over several kinds of operations.
+///
+/// Records added by [`RecordExtension::add_record`](crate::prelude::RecordExtension::add_record)
+/// are registered automatically; otherwise, call [`GlobalRecord::register`].
+#[derive(Resource, Default)]
+pub struct GlobalRecord {
+    records: Vec<RegisteredRecord>,
+}
+
+struct RegisteredRecord {
+    type_id: TypeId,
+    latest_stamp: fn(&World) -> Option<u64>,
+    err_if_locked: fn(&World) -> EditRecordResult,
+    undo: UndoFn,
+}
+
+impl GlobalRecord {
+    /// Registers [`Record<Act>`] as the target of the global undo.
+    ///
+    /// Registering the same `Act` twice has no effect.
+    pub fn register<Act>(&mut self)
+    where
+        Act: Send + Sync + 'static,
+    {
+        if self.is_registered::<Act>() {
+            return;
+        }
+        self.records.push(RegisteredRecord {
+            type_id: TypeId::of::<Act>(),
+            latest_stamp: |world| {
+                world
+                    .get_resource::<Record<Act>>()
+                    .and_then(Record::latest_stamp)
+            },
+            err_if_locked: |world| {
+                world
+                    .get_resource::<Record<Act>>()
+                    .map_or(Ok(()), Record::err_if_locked)
+            },
+            undo: crate::action::record::undo::once::<Act>,
+        });
+    }
+
+    /// Returns true if [`Record<Act>`] is registered.
+    #[inline]
+    pub fn is_registered<Act: 'static>(&self) -> bool {
+        let type_id = TypeId::of::<Act>();
+        self.records.iter().any(|r| r.type_id == type_id)
+    }
+
+    /// Returns `undo::once` of the record holding the most recently pushed track.
+    ///
+    /// Returns an error if any registered record can't be edited.
+    pub(crate) fn latest_undo(&self, world: &World) -> Result<Option<UndoFn>, EditRecordError> {
+        for record in &self.records {
+            (record.err_if_locked)(world)?;
+        }
+        Ok(self
+            .records
+            .iter()
+            .filter_map(|r| Some(((r.latest_stamp)(world)?, r.undo)))
+            .max_by_key(|(stamp, _)| *stamp)
+            .map(|(_, undo)| undo))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once, record};
+    use crate::prelude::*;
+    use crate::tests::{increment_count, test_app, NumAct, TestAct};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    fn push_num_act(num: usize) -> ActionSeed<(), EditRecordResult> {
+        record::push()
+            .with(Track {
+                act: NumAct(num),
+                rollback: Rollback::undo(move || {
+                    once::run(move |mut count: ResMut<Count>| {
+                        count.0 = num;
+                    })
+                }),
+            })
+            .omit_input()
+    }
+
+    fn push_test_act() -> ActionSeed<(), EditRecordResult> {
+        record::push()
+            .with(Track {
+                act: TestAct,
+                rollback: Rollback::undo(increment_count),
+            })
+            .omit_input()
+    }
+
+    #[test]
+    fn registered_by_add_record() {
+        let app = test_app();
+        app.assert_resource(true, |global: &GlobalRecord| {
+            global.is_registered::<TestAct>() && global.is_registered::<NumAct>()
+        });
+    }
+
+    #[test]
+    fn undo_in_push_order() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, push_num_act(10).then(push_test_act()))
+                    .await
+                    .unwrap();
+                task.will(Update, record::undo::global()).await.unwrap();
+                task.will(Update, record::undo::global()).await.unwrap();
+            }));
+        });
+        app.update();
+        // If the order is reversed, the count will be 11.
+        app.assert_resource_eq(Count(10));
+    }
+
+    #[test]
+    fn redone_track_becomes_latest() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    push_test_act()
+                        .then(record::push().with(Track {
+                            act: NumAct(10),
+                            rollback: Rollback::undo_redo(|| {
+                                once::run(|mut count: ResMut<Count>| {
+                                    count.0 = 10;
+                                })
+                                .map(|_| RedoAction::new(once::run(|| {})))
+                            }),
+                        }))
+                        .then(record::undo::once::<TestAct>())
+                        .then(record::undo::once::<NumAct>())
+                        .then(record::redo::once::<NumAct>())
+                        .then(push_test_act()),
+                )
+                .await
+                .unwrap();
+                task.will(Update, record::undo::global()).await.unwrap();
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(11));
+    }
+
+    #[test]
+    fn nothing_happens_if_empty() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, record::undo::global()).await.unwrap();
+                task.will(Update, increment_count()).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn err_if_any_record_in_progress() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    push_num_act(10)
+                        .then(record::push().with(Track {
+                            act: TestAct,
+                            rollback: Rollback::undo(|| delay::frames().with(1)),
+                        }))
+                        .then(record::undo::once::<TestAct>()),
+                )
+                .await
+                .unwrap();
+            }));
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::frames().with(0)).await;
+                let result = task.will(Update, record::undo::global()).await;
+                task.will(
+                    Update,
+                    once::res::insert().with(Count(result.is_err() as usize)),
+                )
+                .await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
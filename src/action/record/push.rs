@@ -7,7 +7,7 @@ use bevy::prelude::World;
 
 /// Push the [`Track`](crate::prelude::Track) onto the [`Record`](crate::prelude::Record).
 ///
-/// The output will be [`UndoRedoInProgress`](crate::prelude::UndoRedoInProgress) if an `undo` or `redo` is in progress.
+/// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress.
 ///
 /// # Examples
 ///
@@ -42,12 +42,50 @@ where
     ActionSeed::new(|track: Track<Act>, output| PushRunner {
         output,
         track: Some(track),
+        keep_redo: false,
+    })
+}
+
+/// Push the [`Track`](crate::prelude::Track) onto the [`Record`](crate::prelude::Record) without clearing the redo stack.
+///
+/// Unlike [`record::push`](push), the tracks undone so far remain redoable.
+/// This is useful for non-destructive tracks such as selection changes.
+///
+/// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct SelectAct;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, record::push_keep_redo()
+///         .with(Track{
+///             act: SelectAct,
+///             rollback: Rollback::undo(|| once::run(||{}))
+///         }.transient()))
+///         .await
+///         .expect("An error will be returned if undo or redo is operating.");
+/// });
+/// ```
+pub fn push_keep_redo<Act>() -> ActionSeed<Track<Act>, EditRecordResult>
+where
+    Act: Send + Sync + 'static,
+{
+    ActionSeed::new(|track: Track<Act>, output| PushRunner {
+        output,
+        track: Some(track),
+        keep_redo: true,
     })
 }
 
 struct PushRunner<Act> {
     track: Option<Track<Act>>,
     output: Output<EditRecordResult>,
+    keep_redo: bool,
 }
 
 impl<Act> Runner for PushRunner<Act>
@@ -56,7 +94,7 @@ where
 {
     fn run(&mut self, world: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
         if let Some(track) = self.track.take() {
-            if let Err(error) = push_track::<Act>(track, world, true) {
+            if let Err(error) = push_track::<Act>(track, world, self.keep_redo) {
                 self.output.set(Err(error));
                 return RunnerIs::Completed;
             }
@@ -123,6 +161,44 @@ mod tests {
         app.assert_resource(1, |h: &Record<H2>| h.tracks.len());
     }
 
+    #[test]
+    fn push_keep_redo() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, push_with_redo(H1)).await;
+                task.will(Update, record::undo::once::<H1>()).await.unwrap();
+                task.will(
+                    Update,
+                    record::push_keep_redo().with(Track {
+                        act: H1,
+                        rollback: Rollback::undo(|| once::run(|| {})),
+                    }),
+                )
+                .await
+                .unwrap();
+            }));
+        });
+        app.update();
+        app.assert_resource(1, |h: &Record<H1>| h.tracks.len());
+        app.assert_resource(1, |h: &Record<H1>| h.redo.len());
+    }
+
+    #[test]
+    fn push_clears_redo() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, push_with_redo(H1)).await;
+                task.will(Update, record::undo::once::<H1>()).await.unwrap();
+                task.will(Update, push(H1)).await;
+            }));
+        });
+        app.update();
+        app.assert_resource(1, |h: &Record<H1>| h.tracks.len());
+        app.assert_resource(0, |h: &Record<H1>| h.redo.len());
+    }
+
     fn push<Act: Send + Sync + 'static>(act: Act) -> ActionSeed {
         record::push()
             .with(Track {
@@ -131,4 +207,13 @@ mod tests {
             })
             .omit()
     }
+
+    fn push_with_redo<Act: Send + Sync + 'static>(act: Act) -> ActionSeed {
+        record::push()
+            .with(Track {
+                act,
+                rollback: Rollback::undo_redo(|| once::run(|| RedoAction::new(once::run(|| {})))),
+            })
+            .omit()
+    }
 }
@@ -3,7 +3,7 @@
 
 use crate::action::record::{push_tracks, Record};
 use crate::action::record::{unlock_record, EditRecordResult};
-use crate::prelude::record::lock_record;
+use crate::prelude::record::{lock_record, run_rollback};
 use crate::prelude::{ActionSeed, Output, Track};
 use crate::runner::{BoxedRunner, CancellationHandlers, CancellationId, Runner, RunnerIs};
 use bevy::prelude::*;
@@ -14,7 +14,7 @@ use bevy::prelude::*;
 ///
 /// If the `redo stack` in [`Record`] is empty, nothing happens.
 ///
-/// The output will be [`UndoRedoInProgress`](crate::prelude::UndoRedoInProgress) if an `undo` or `redo` is in progress.
+/// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress.
 ///
 /// # Examples
 ///
@@ -50,7 +50,7 @@ where
 ///
 /// If the `redo stack` in [`Record`] is empty, nothing happens.
 ///
-/// The output will be [`UndoRedoInProgress`](crate::prelude::UndoRedoInProgress) if an `undo` or `redo` is in progress.
+/// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress.
 pub fn index_to<Act>() -> ActionSeed<usize, EditRecordResult>
 where
     Act: Send + Sync + 'static,
@@ -62,7 +62,7 @@ where
 ///
 /// If the `redo stack` in [`Record`] is empty, nothing happens.
 ///
-/// The output will be [`UndoRedoInProgress`](crate::prelude::UndoRedoInProgress) if an `undo` or `redo` is in progress.
+/// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress.
 pub fn to<Act>() -> ActionSeed<Act, EditRecordResult>
 where
     Act: Send + Sync + PartialEq + 'static,
@@ -83,7 +83,7 @@ where
 ///
 /// If the `redo stack` in [`Record`] is empty, nothing happens.
 ///
-/// The output will be [`UndoRedoInProgress`](crate::prelude::UndoRedoInProgress) if an `undo` or `redo` is in progress.
+/// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress.
 pub fn all<Act>() -> ActionSeed<(), EditRecordResult>
 where
     Act: Send + Sync + 'static,
@@ -158,7 +158,13 @@ where
                 }
             }
 
-            match self.redo_runner.as_mut().unwrap().run(world, token) {
+            let redo_runner = self.redo_runner.as_mut().unwrap();
+            match run_rollback::<Act>(
+                redo_runner,
+                world,
+                token,
+                (self.cancellation_id, cleanup::<Act>),
+            ) {
                 RunnerIs::Completed => {
                     self.redo_runner.take();
                     self.redo_output.take();
@@ -0,0 +1,316 @@
+use crate::action::record::{push, EditRecordResult};
+use crate::prelude::{once, ActionSeed, Pipe, RedoAction, Rollback, Track};
+use bevy::ecs::reflect::{AppTypeRegistry, ReflectComponent};
+use bevy::prelude::*;
+use bevy::reflect::PartialReflect;
+use core::any::TypeId;
+
+impl<Act> Track<Act>
+where
+    Act: Send + Sync + 'static,
+{
+    /// Captures the current value of the component `C` on `entity`, and then pushes the [`Track`]
+    /// whose `undo` restores the captured value.
+    ///
+    /// The value at the time of `undo` is reapplied by `redo`.
+    /// If the entity doesn't have `C` when captured, `undo` removes it.
+    ///
+    /// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// struct MoveAct;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let entity = task.will(Update, once::run(|mut commands: Commands|{
+    ///         commands.spawn(Transform::default()).id()
+    ///     })).await;
+    ///     task.will(Update, Track::snapshot::<Transform>(entity).with(MoveAct))
+    ///         .await
+    ///         .expect("An error will be returned if undo or redo is operating.");
+    ///     task.will(Update, once::run(move |mut transforms: Query<&mut Transform>|{
+    ///         transforms.get_mut(entity).unwrap().translation = Vec3::X;
+    ///     })).await;
+    ///     // Restore the translation to `Vec3::ZERO`.
+    ///     task.will(Update, record::undo::once::<MoveAct>()).await.unwrap();
+    /// });
+    /// ```
+    pub fn snapshot<C>(entity: Entity) -> ActionSeed<Act, EditRecordResult>
+    where
+        C: Component + Clone,
+    {
+        once::run(move |In(act): In<Act>, components: Query<&C>| {
+            let before = components.get(entity).ok().cloned();
+            Track {
+                act,
+                rollback: Rollback::undo_redo(move || {
+                    let before = before.clone();
+                    once::run(move |world: &mut World| {
+                        let after = replace_component(world, entity, before.clone());
+                        RedoAction::new(once::run(move |world: &mut World| {
+                            replace_component(world, entity, after.clone());
+                        }))
+                    })
+                }),
+            }
+        })
+        .pipe(push())
+    }
+
+    /// Captures the current value of the resource `R`, and then pushes the [`Track`]
+    /// whose `undo` restores the captured value.
+    ///
+    /// The value at the time of `undo` is reapplied by `redo`.
+    /// If the resource doesn't exist when captured, `undo` removes it.
+    ///
+    /// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress.
+    pub fn snapshot_resource<R>() -> ActionSeed<Act, EditRecordResult>
+    where
+        R: Resource + Clone,
+    {
+        once::run(|In(act): In<Act>, resource: Option<Res<R>>| {
+            let before = resource.map(|r| r.clone());
+            Track {
+                act,
+                rollback: Rollback::undo_redo(move || {
+                    let before = before.clone();
+                    once::run(move |world: &mut World| {
+                        let after = replace_resource(world, before.clone());
+                        RedoAction::new(once::run(move |world: &mut World| {
+                            replace_resource(world, after.clone());
+                        }))
+                    })
+                }),
+            }
+        })
+        .pipe(push())
+    }
+
+    /// Captures the current value of the component identified by `type_id` on `entity`
+    /// through reflection, and then pushes the [`Track`] whose `undo` restores the captured value.
+    ///
+    /// The component must be registered in [`AppTypeRegistry`] with [`ReflectComponent`].
+    /// Otherwise, `undo` and `redo` do nothing.
+    ///
+    /// The value at the time of `undo` is reapplied by `redo`.
+    /// If the entity doesn't have the component when captured, `undo` removes it.
+    ///
+    /// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress.
+    pub fn snapshot_reflect(entity: Entity, type_id: TypeId) -> ActionSeed<Act, EditRecordResult> {
+        once::run(move |In(act): In<Act>, world: &World| {
+            let before = reflect_component(world, entity, type_id);
+            Track {
+                act,
+                rollback: Rollback::undo_redo(move || {
+                    let before = before.as_deref().map(PartialReflect::to_dynamic);
+                    once::run(move |world: &mut World| {
+                        let after = replace_reflect_component(world, entity, type_id, &before);
+                        RedoAction::new(once::run(move |world: &mut World| {
+                            replace_reflect_component(world, entity, type_id, &after);
+                        }))
+                    })
+                }),
+            }
+        })
+        .pipe(push())
+    }
+}
+
+fn replace_component<C: Component + Clone>(
+    world: &mut World,
+    entity: Entity,
+    value: Option<C>,
+) -> Option<C> {
+    let Ok(mut entity) = world.get_entity_mut(entity) else {
+        return None;
+    };
+    let current = entity.get::<C>().cloned();
+    match value {
+        Some(value) => {
+            entity.insert(value);
+        }
+        None => {
+            entity.remove::<C>();
+        }
+    }
+    current
+}
+
+fn replace_resource<R: Resource + Clone>(world: &mut World, value: Option<R>) -> Option<R> {
+    let current = world.get_resource::<R>().cloned();
+    match value {
+        Some(value) => world.insert_resource(value),
+        None => {
+            world.remove_resource::<R>();
+        }
+    }
+    current
+}
+
+fn reflect_component(
+    world: &World,
+    entity: Entity,
+    type_id: TypeId,
+) -> Option<Box<dyn PartialReflect>> {
+    let registry = world.get_resource::<AppTypeRegistry>()?.read();
+    let reflect = registry.get_type_data::<ReflectComponent>(type_id)?;
+    let entity = world.get_entity(entity).ok()?;
+    reflect
+        .reflect(entity)
+        .map(|component| component.to_dynamic())
+}
+
+fn replace_reflect_component(
+    world: &mut World,
+    entity: Entity,
+    type_id: TypeId,
+    value: &Option<Box<dyn PartialReflect>>,
+) -> Option<Box<dyn PartialReflect>> {
+    let current = reflect_component(world, entity, type_id);
+    let registry = world.get_resource::<AppTypeRegistry>()?.clone();
+    let registry = registry.read();
+    let reflect = registry.get_type_data::<ReflectComponent>(type_id)?;
+    let mut entity = world.get_entity_mut(entity).ok()?;
+    match value {
+        Some(value) => reflect.insert(&mut entity, value.as_ref(), &registry),
+        None => reflect.remove(&mut entity),
+    }
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, record};
+    use crate::prelude::*;
+    use crate::tests::{test_app, TestAct};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::*;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use core::any::TypeId;
+
+    #[derive(Component, Reflect, Clone, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Value(usize);
+
+    fn spawn_value(app: &mut App) -> Entity {
+        app.world_mut().spawn(Value(1)).id()
+    }
+
+    fn set_value(entity: Entity, value: usize) -> ActionSeed {
+        once::run(move |mut commands: Commands| {
+            commands.entity(entity).insert(Value(value));
+        })
+    }
+
+    fn assert_value(app: &mut App, entity: Entity, expect: Option<Value>) {
+        assert_eq!(app.world().get::<Value>(entity), expect.as_ref());
+    }
+
+    #[test]
+    fn undo_redo_component() {
+        let mut app = test_app();
+        let entity = spawn_value(&mut app);
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, Track::snapshot::<Value>(entity).with(TestAct))
+                    .await
+                    .unwrap();
+                task.will(Update, set_value(entity, 2)).await;
+                task.will(Update, record::undo::once::<TestAct>())
+                    .await
+                    .unwrap();
+                task.will(Update, delay::frames().with(1)).await;
+                task.will(Update, record::redo::once::<TestAct>())
+                    .await
+                    .unwrap();
+            }));
+        });
+        app.update();
+        assert_value(&mut app, entity, Some(Value(1)));
+        app.update();
+        assert_value(&mut app, entity, Some(Value(2)));
+    }
+
+    #[test]
+    fn undo_removes_component_added_after_snapshot() {
+        let mut app = test_app();
+        let entity = app.world_mut().spawn_empty().id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, Track::snapshot::<Value>(entity).with(TestAct))
+                    .await
+                    .unwrap();
+                task.will(Update, set_value(entity, 2)).await;
+                task.will(Update, record::undo::once::<TestAct>())
+                    .await
+                    .unwrap();
+                task.will(Update, delay::frames().with(1)).await;
+                task.will(Update, record::redo::once::<TestAct>())
+                    .await
+                    .unwrap();
+            }));
+        });
+        app.update();
+        assert_value(&mut app, entity, None);
+        app.update();
+        assert_value(&mut app, entity, Some(Value(2)));
+    }
+
+    #[test]
+    fn undo_redo_resource() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, Track::snapshot_resource::<Count>().with(TestAct))
+                    .await
+                    .unwrap();
+                task.will(Update, once::res::insert().with(Count(3))).await;
+                task.will(Update, record::undo::once::<TestAct>())
+                    .await
+                    .unwrap();
+                task.will(Update, delay::frames().with(1)).await;
+                task.will(Update, record::redo::once::<TestAct>())
+                    .await
+                    .unwrap();
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn undo_redo_reflect_component() {
+        let mut app = test_app();
+        app.register_type::<Value>();
+        let entity = spawn_value(&mut app);
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(
+                    Update,
+                    Track::snapshot_reflect(entity, TypeId::of::<Value>()).with(TestAct),
+                )
+                .await
+                .unwrap();
+                task.will(Update, set_value(entity, 2)).await;
+                task.will(Update, record::undo::once::<TestAct>())
+                    .await
+                    .unwrap();
+                task.will(Update, delay::frames().with(1)).await;
+                task.will(Update, record::redo::once::<TestAct>())
+                    .await
+                    .unwrap();
+            }));
+        });
+        app.update();
+        assert_value(&mut app, entity, Some(Value(1)));
+        app.update();
+        assert_value(&mut app, entity, Some(Value(2)));
+    }
+}
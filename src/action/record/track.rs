@@ -14,21 +14,64 @@ pub struct Track<Act> {
     pub rollback: Rollback,
 }
 
+impl<Act> Track<Act> {
+    /// Marks this track as transient.
+    ///
+    /// Transient tracks, such as selection changes, are skipped by
+    /// [`record::undo`](crate::prelude::record::undo) actions
+    /// unless explicitly requested by [`record::undo::once_including_transient`](crate::prelude::record::undo::once_including_transient).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// struct SelectAct;
+    ///
+    /// Track {
+    ///     act: SelectAct,
+    ///     rollback: Rollback::undo(|| once::run(||{})),
+    /// }
+    ///     .transient();
+    /// ```
+    #[inline]
+    pub fn transient(mut self) -> Self {
+        self.rollback.transient = true;
+        self
+    }
+
+    /// Returns true if this track is transient.
+    #[inline]
+    pub const fn is_transient(&self) -> bool {
+        self.rollback.transient
+    }
+}
+
 impl<Act> Track<Act>
 where
     Act: Send + Sync + 'static,
 {
     #[inline]
     pub(crate) fn create_runner(&self, output: Output<Option<ActionSeed>>) -> BoxedRunner {
-        (self.rollback.0)().create_runner(output)
+        (self.rollback.f)().create_runner(output)
     }
 }
 
 /// This structure holds the function that will be called when an `undo` operation is requested on the track that holds it.
-#[repr(transparent)]
-pub struct Rollback(Box<dyn Fn() -> Action<(), Option<ActionSeed>> + Send + Sync>);
+pub struct Rollback {
+    f: Box<dyn Fn() -> Action<(), Option<ActionSeed>> + Send + Sync>,
+    transient: bool,
+}
 
 impl Rollback {
+    #[inline]
+    fn from_fn(f: impl Fn() -> Action<(), Option<ActionSeed>> + Send + Sync + 'static) -> Self {
+        Self {
+            f: Box::new(f),
+            transient: false,
+        }
+    }
+
     /// Create a [`Rollback`] with the function creates `undo action`.
     ///
     /// Its action's output is [`Option<RedoAction>`], which for [`Option::Some`] creates a `redo action`
@@ -54,9 +97,7 @@ impl Rollback {
         F: Fn() -> A + Send + Sync + 'static,
         A: Into<Action<I, Option<RedoAction>>> + Send + Sync + 'static,
     {
-        Self(Box::new(move || {
-            f().omit_input().map(|redo| redo.map(|r| r.0)).with(())
-        }))
+        Self::from_fn(move || f().omit_input().map(|redo| redo.map(|r| r.0)).with(()))
     }
 
     /// Create a [`Rollback`] with the function creates `undo action`.
@@ -78,7 +119,7 @@ impl Rollback {
         F: Fn() -> A + Send + Sync + 'static,
         A: Into<Action<I, O>> + Send + Sync + 'static,
     {
-        Self(Box::new(move || f().omit_input().map(|_| None).with(())))
+        Self::from_fn(move || f().omit_input().map(|_| None).with(()))
     }
 
     /// Create a Restore with the function creates undo action.
@@ -102,9 +143,7 @@ impl Rollback {
         F: Fn() -> A + Send + Sync + 'static,
         A: Into<Action<I, RedoAction>> + Send + Sync + 'static,
     {
-        Self(Box::new(move || {
-            f().omit_input().map(|redo| Some(redo.0)).with(())
-        }))
+        Self::from_fn(move || f().omit_input().map(|redo| Some(redo.0)).with(()))
     }
 
     /// Declare undo and redo separately.
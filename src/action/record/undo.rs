@@ -1,17 +1,22 @@
 //! Define the actions related to `undo` operations.
 //!
 //! To perform these the actions, you must call the [`record::push`](crate::prelude::record::push) beforehand.
+//!
+//! [Transient](Track::transient) tracks are skipped and remain in the record,
+//! except for [`once_including_transient`].
 
+use crate::action::once;
+use crate::action::record::global::UndoFn;
 use crate::action::record::EditRecordResult;
-use crate::action::record::Record;
-use crate::prelude::record::{lock_record, unlock_record};
-use crate::prelude::{ActionSeed, Output, Runner, Track};
+use crate::action::record::{GlobalRecord, Record};
+use crate::prelude::record::{lock_record, run_rollback, unlock_record};
+use crate::prelude::{ActionSeed, Output, Pipe, Runner, Track};
 use crate::runner::{BoxedRunner, CancellationHandlers, CancellationId, RunnerIs};
 use bevy::prelude::*;
 
-/// Pops the last pushed `undo` action, and then execute it.
+/// Pops the last pushed `undo` action that is not [transient](Track::transient), and then execute it.
 ///
-/// The output will be [`UndoRedoInProgress`](crate::prelude::UndoRedoInProgress) if an `undo` or `redo` is in progress.
+/// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress.
 ///
 /// ```no_run
 /// use bevy::prelude::*;
@@ -36,23 +41,43 @@ where
     Act: Send + Sync + 'static,
 {
     do_undo(|_: ()| {
-        move |record: &mut Record<Act>| record.tracks.pop().map(|t| vec![t]).unwrap_or_default()
+        move |record: &mut Record<Act>| {
+            let Some(last) = record.tracks.iter().rposition(|t| !t.is_transient()) else {
+                return Vec::new();
+            };
+            record.take_tracks(last, false)
+        }
+    })
+}
+
+/// Pops the last pushed `undo` action even if it is [transient](Track::transient), and then execute it.
+///
+/// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress.
+pub fn once_including_transient<Act>() -> ActionSeed<(), EditRecordResult>
+where
+    Act: Send + Sync + 'static,
+{
+    do_undo(|_: ()| {
+        move |record: &mut Record<Act>| {
+            let last = record.tracks.len().saturating_sub(1);
+            record.take_tracks(last, true)
+        }
     })
 }
 
 /// Pops `undo` up to the specified index.
 ///
-/// The output will be [`UndoRedoInProgress`](crate::prelude::UndoRedoInProgress) if an `undo` or `redo` is in progress.
+/// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress.
 pub fn index_to<Act>() -> ActionSeed<usize, EditRecordResult>
 where
     Act: Send + Sync + 'static,
 {
-    do_undo(|index: usize| move |record: &mut Record<Act>| record.tracks.split_off(index))
+    do_undo(|index: usize| move |record: &mut Record<Act>| record.take_tracks(index, false))
 }
 
 /// Pops `undo` until the specified operation is reached.
 ///
-/// The output will be [`UndoRedoInProgress`](crate::prelude::UndoRedoInProgress) if an `undo` or `redo` is in progress.
+/// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress.
 pub fn to<Act>() -> ActionSeed<Act, EditRecordResult>
 where
     Act: Send + Sync + PartialEq + 'static,
@@ -64,19 +89,70 @@ where
                 .iter()
                 .position(|t| t.act == to)
                 .unwrap_or_default();
-            record.tracks.split_off(pos)
+            record.take_tracks(pos, false)
         }
     })
 }
 
 /// Pops all the `undo` actions from [`Record`].
 ///
-/// The output will be [`UndoRedoInProgress`](crate::prelude::UndoRedoInProgress) if an `undo` or `redo` is in progress.
+/// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress.
 pub fn all<Act>() -> ActionSeed<(), EditRecordResult>
 where
     Act: Send + Sync + 'static,
 {
-    do_undo(|_: ()| |record: &mut Record<Act>| core::mem::take(&mut record.tracks))
+    do_undo(|_: ()| |record: &mut Record<Act>| record.take_tracks(0, false))
+}
+
+/// Executes [`once`] on the registered record that holds the most recently pushed track.
+///
+/// This allows a single undo across multiple `Act` types.
+/// See [`GlobalRecord`] for how records are registered.
+///
+/// The output will be [`EditRecordError::UndoRedoInProgress`](crate::prelude::EditRecordError::UndoRedoInProgress) if an `undo` or `redo` is in progress
+/// in any of the registered records.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct MoveAct;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct ColorAct;
+///
+/// App::new()
+///     .add_record::<MoveAct>()
+///     .add_record::<ColorAct>();
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, record::push().with(Track{
+///         act: MoveAct,
+///         rollback: Rollback::undo(|| once::run(||{}))
+///     })).await.unwrap();
+///     task.will(Update, record::push().with(Track{
+///         act: ColorAct,
+///         rollback: Rollback::undo(|| once::run(||{}))
+///     })).await.unwrap();
+///     // Undo `ColorAct`, and then `MoveAct`.
+///     task.will(Update, record::undo::global()).await.unwrap();
+///     task.will(Update, record::undo::global()).await.unwrap();
+/// });
+/// ```
+pub fn global() -> ActionSeed<(), EditRecordResult> {
+    once::run(|world: &World| {
+        world
+            .get_resource::<GlobalRecord>()
+            .map_or(Ok(None), |global| global.latest_undo(world))
+    })
+    .pipe(ActionSeed::define(
+        |latest: Result<Option<UndoFn>, _>| match latest {
+            Ok(Some(undo)) => undo().with(()),
+            Ok(None) => once::run(|| Ok(())).with(()),
+            Err(e) => once::run(move || Err(e)).with(()),
+        },
+    ))
 }
 
 fn do_undo<I, Act, F>(
@@ -146,7 +222,16 @@ where
                 return RunnerIs::Completed;
             };
 
-            undo_runner.run(world, token);
+            if run_rollback::<Act>(
+                undo_runner,
+                world,
+                token,
+                (self.cancellation_id, cleanup::<Act>),
+            )
+            .is_cancel()
+            {
+                return RunnerIs::Canceled;
+            }
             let Some(redo) = self.undo_output.take() else {
                 return RunnerIs::Running;
             };
@@ -434,4 +519,151 @@ mod tests {
         app.update();
         app.assert_resource(false, |record: &Record<TestAct>| record.can_edit());
     }
+
+    fn push_transient_add_ten() -> ActionSeed<(), EditRecordResult> {
+        record::push()
+            .with(
+                Track {
+                    act: TestAct,
+                    rollback: Rollback::undo(|| {
+                        once::run(|mut count: ResMut<Count>| {
+                            count.0 += 10;
+                        })
+                    }),
+                }
+                .transient(),
+            )
+            .omit_input()
+    }
+
+    #[test]
+    fn once_skips_transient() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    push_undo_increment()
+                        .then(push_transient_add_ten())
+                        .then(record::undo::once::<TestAct>()),
+                )
+                .await
+                .unwrap();
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.assert_resource(true, |record: &Record<TestAct>| {
+            record.tracks.len() == 1 && record.tracks[0].is_transient()
+        });
+    }
+
+    #[test]
+    fn all_skips_transient() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    push_undo_increment()
+                        .then(push_transient_add_ten())
+                        .then(push_undo_increment())
+                        .then(record::undo::all::<TestAct>()),
+                )
+                .await
+                .unwrap();
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(2));
+        app.assert_resource(1, |record: &Record<TestAct>| record.tracks.len());
+    }
+
+    #[test]
+    fn once_including_transient() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    push_undo_increment()
+                        .then(push_transient_add_ten())
+                        .then(record::undo::once_including_transient::<TestAct>()),
+                )
+                .await
+                .unwrap();
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(10));
+        app.assert_resource(1, |record: &Record<TestAct>| record.tracks.len());
+    }
+
+    #[test]
+    fn unlock_if_reactor_despawned_while_running_rollback() {
+        let mut app = test_app();
+        app.spawn_reactor(|task| async move {
+            task.will(Update, {
+                record::push()
+                    .with(Track {
+                        act: TestAct,
+                        rollback: Rollback::undo(|| {
+                            once::run(
+                                |mut commands: Commands,
+                                 reactor: Query<Entity, With<NativeReactor>>| {
+                                    commands.entity(reactor.single().unwrap()).despawn();
+                                },
+                            )
+                            .then(delay::frames().with(100))
+                        }),
+                    })
+                    .then(record::undo::once::<TestAct>())
+            })
+            .await
+            .unwrap();
+        });
+        app.update();
+        app.update();
+        app.assert_resource(true, |record: &Record<TestAct>| record.can_edit());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn poisoned_if_rollback_panicked() {
+        let mut app = test_app();
+        app.spawn_reactor(|task| async move {
+            task.will(Update, {
+                record::push()
+                    .with(Track {
+                        act: TestAct,
+                        rollback: Rollback::undo(|| {
+                            once::run(|| {
+                                panic!("rollback failed");
+                            })
+                        }),
+                    })
+                    .then(record::undo::once::<TestAct>())
+            })
+            .await
+            .unwrap();
+        });
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| app.update()));
+        assert!(result.is_err());
+
+        let mut record = app.world_mut().resource_mut::<Record<TestAct>>();
+        assert!(record.is_poisoned());
+        assert!(!record.can_edit());
+        let track = Track {
+            act: TestAct,
+            rollback: Rollback::undo(|| once::run(|| {})),
+        };
+        assert_eq!(record.push(track), Err(EditRecordError::RecordPoisoned));
+
+        record.force_reset();
+        let track = Track {
+            act: TestAct,
+            rollback: Rollback::undo(|| once::run(|| {})),
+        };
+        assert_eq!(record.push(track), Ok(()));
+    }
 }
@@ -136,7 +136,7 @@ mod tests {
     use crate::action::once;
     use crate::action::sequence::Then;
     use crate::prelude::Reactor;
-    use crate::test_util::test;
+    use crate::testing;
     use crate::tests::{increment_count, test_app};
 
     #[derive(Resource, Eq, PartialEq, Debug)]
@@ -264,7 +264,7 @@ mod tests {
         let mut app = test_app();
         app.add_systems(Startup, |mut commands: Commands| {
             commands.spawn(Reactor::schedule(|task| async move {
-                task.will(Update, test::cancel().then(increment_count()))
+                task.will(Update, testing::cancel().then(increment_count()))
                     .await;
             }));
         });
@@ -1,9 +1,13 @@
 //! Convert the operations with side effects such as asynchronous runtime or thread
 //! into the referential-transparent actions.
 
+pub use cancellation_token::{CancellationToken, Cancelled};
 use core::future::Future;
 
 pub mod bevy_task;
+mod cancellation_token;
+pub mod channel;
+pub mod stream;
 #[cfg(all(not(target_arch = "wasm32"), feature = "std"))]
 pub mod thread;
 #[cfg(feature = "tokio")]
@@ -14,8 +18,10 @@ pub mod tokio;
 pub trait AsyncFunctor<I, Out, M> {
     /// Returns a new future with input.
     ///
+    /// The `token` is cancelled when the [`Reactor`](crate::prelude::Reactor) is canceled.
+    ///
     /// If you have added the `tokio` feature flag, future will be automatically compat.
-    fn functor(self, input: I) -> impl Future<Output = Out> + Send;
+    fn functor(self, input: I, token: CancellationToken) -> impl Future<Output = Out> + Send;
 }
 
 impl<I, F, Fut> AsyncFunctor<I, <Fut as Future>::Output, ()> for F
@@ -26,7 +32,11 @@ where
     <Fut as Future>::Output: Send + 'static,
 {
     #[inline]
-    fn functor(self, input: I) -> impl Future<Output = <Fut as Future>::Output> + Send {
+    fn functor(
+        self,
+        input: I,
+        _: CancellationToken,
+    ) -> impl Future<Output = <Fut as Future>::Output> + Send {
         #[cfg(all(not(target_arch = "wasm32"), feature = "tokio"))]
         {
             use async_compat::CompatExt;
@@ -39,6 +49,31 @@ where
     }
 }
 
+impl<I, F, Fut> AsyncFunctor<I, <Fut as Future>::Output, CancellationToken> for F
+where
+    I: Send + 'static,
+    F: FnOnce(I, CancellationToken) -> Fut + Send + 'static,
+    Fut: Future + Send + 'static,
+    <Fut as Future>::Output: Send + 'static,
+{
+    #[inline]
+    fn functor(
+        self,
+        input: I,
+        token: CancellationToken,
+    ) -> impl Future<Output = <Fut as Future>::Output> + Send {
+        #[cfg(all(not(target_arch = "wasm32"), feature = "tokio"))]
+        {
+            use async_compat::CompatExt;
+            self(input, token).compat()
+        }
+        #[cfg(any(target_arch = "wasm32", not(feature = "tokio")))]
+        {
+            self(input, token)
+        }
+    }
+}
+
 impl<I, Fut> AsyncFunctor<I, <Fut as Future>::Output, bool> for Fut
 where
     I: Send + 'static,
@@ -46,7 +81,11 @@ where
     <Fut as Future>::Output: Send + 'static,
 {
     #[inline]
-    fn functor(self, _: I) -> impl Future<Output = <Fut as Future>::Output> + Send {
+    fn functor(
+        self,
+        _: I,
+        _: CancellationToken,
+    ) -> impl Future<Output = <Fut as Future>::Output> + Send {
         #[cfg(all(not(target_arch = "wasm32"), feature = "tokio"))]
         {
             use async_compat::CompatExt;
@@ -62,7 +101,9 @@ where
 /// This trait is used in the action argument and does not need to be implemented explicitly by the user
 pub trait Functor<I, O, M> {
     /// Returns a new function input.
-    fn functor(self, input: I) -> impl FnOnce() -> O + Send + 'static;
+    ///
+    /// The `token` is cancelled when the [`Reactor`](crate::prelude::Reactor) is canceled.
+    fn functor(self, input: I, token: CancellationToken) -> impl FnOnce() -> O + Send + 'static;
 }
 
 impl<I, O, F> Functor<I, O, ()> for F
@@ -71,17 +112,28 @@ where
     F: FnOnce(I) -> O + Send + 'static,
 {
     #[inline]
-    fn functor(self, input: I) -> impl FnOnce() -> O + Send + 'static {
+    fn functor(self, input: I, _: CancellationToken) -> impl FnOnce() -> O + Send + 'static {
         move || self(input)
     }
 }
 
+impl<I, O, F> Functor<I, O, CancellationToken> for F
+where
+    I: Send + 'static,
+    F: FnOnce(I, CancellationToken) -> O + Send + 'static,
+{
+    #[inline]
+    fn functor(self, input: I, token: CancellationToken) -> impl FnOnce() -> O + Send + 'static {
+        move || self(input, token)
+    }
+}
+
 impl<O, F> Functor<(), O, bool> for F
 where
     F: FnOnce() -> O + Send + 'static,
 {
     #[inline]
-    fn functor(self, _input: ()) -> impl FnOnce() -> O + Send + 'static {
+    fn functor(self, _input: (), _: CancellationToken) -> impl FnOnce() -> O + Send + 'static {
         || self()
     }
 }
@@ -1,11 +1,15 @@
 use bevy::prelude::World;
 
-use crate::action::side_effect::AsyncFunctor;
-use crate::prelude::{ActionSeed, CancellationHandlers, Output, Runner, RunnerIs};
+use crate::action::side_effect::{AsyncFunctor, CancellationToken};
+use crate::prelude::{ActionSeed, CancellationHandlers, CancellationId, Output, Runner, RunnerIs};
+use core::task::Poll;
 
 /// Spawns a future onto the bevy thread pool,
 /// and then wait until its completed.
 ///
+/// The task is dropped when [`Reactor`](crate::prelude::Reactor) is canceled.
+/// If the function accepts [`CancellationToken`] as the second argument, it is also cancelled at that time.
+///
 /// ```no_run
 ///
 /// use bevy::prelude::*;
@@ -36,12 +40,17 @@ where
     Out: Send + 'static,
     M: Send + 'static,
 {
-    ActionSeed::new(|input, output| BevyTaskRunner {
-        output,
-        #[cfg(not(target_arch = "wasm32"))]
-        task: bevy::tasks::AsyncComputeTaskPool::get().spawn(f.functor(input)),
-        #[cfg(target_arch = "wasm32")]
-        task: Box::pin(f.functor(input)),
+    ActionSeed::new(|input, output| {
+        let token = CancellationToken::new();
+        BevyTaskRunner {
+            output,
+            #[cfg(not(target_arch = "wasm32"))]
+            task: bevy::tasks::AsyncComputeTaskPool::get().spawn(f.functor(input, token.clone())),
+            #[cfg(target_arch = "wasm32")]
+            task: Box::pin(f.functor(input, token.clone())),
+            token,
+            cancellation_id: None,
+        }
     })
 }
 
@@ -51,6 +60,8 @@ struct BevyTaskRunner<Out> {
     #[cfg(target_arch = "wasm32")]
     task: std::pin::Pin<Box<dyn std::future::Future<Output = Out>>>,
     output: Output<Out>,
+    token: CancellationToken,
+    cancellation_id: Option<CancellationId>,
 }
 
 impl<Out> Runner for BevyTaskRunner<Out>
@@ -58,12 +69,20 @@ where
     Out: Send + 'static,
 {
     #[allow(clippy::async_yields_async)]
-    fn run(&mut self, _: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
-        if let Some(out) = pollster::block_on(futures_lite::future::poll_once(&mut self.task)) {
+    fn run(&mut self, _: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        if self.cancellation_id.is_none() {
+            let cancellation_token = self.token.clone();
+            self.cancellation_id
+                .replace(token.register(move |_| cancellation_token.cancel()));
+        }
+        if let Poll::Ready(out) = token.poll(&mut self.task) {
+            if let Some(id) = self.cancellation_id.as_ref() {
+                token.unregister(id);
+            }
             self.output.set(out);
             RunnerIs::Completed
         } else {
-            RunnerIs::Running
+            token.pending()
         }
     }
 }
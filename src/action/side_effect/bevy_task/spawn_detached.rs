@@ -1,5 +1,5 @@
-use crate::action::side_effect::AsyncFunctor;
-use crate::prelude::{ActionSeed, CancellationHandlers, Output, RunnerIs};
+use crate::action::side_effect::{AsyncFunctor, CancellationToken};
+use crate::prelude::{ActionSeed, CancellationHandlers, CancellationId, Output, RunnerIs};
 use crate::runner::Runner;
 use alloc::sync::Arc;
 use bevy::platform::sync::Mutex;
@@ -13,7 +13,8 @@ use core::marker::PhantomData;
 /// Unlike [`side_effect::bevy_task::spawn`](crate::prelude::side_effect::bevy_task::spawn_detached),
 /// a spawned task is detached and continues to run in the background.
 ///
-/// Note that tasks created from this function will continue to run even if [`Reactor`](crate::prelude::Reactor) is canceled.
+/// When [`Reactor`](crate::prelude::Reactor) is canceled, the task stops at its next await point.
+/// If the function accepts [`CancellationToken`] as the second argument, it is also cancelled at that time.
 ///
 /// ```no_run
 ///
@@ -49,6 +50,8 @@ where
         output,
         arc_output: Arc::new(Mutex::new(None)),
         args: Some((input, functor)),
+        token: CancellationToken::new(),
+        cancellation_id: None,
         _m: PhantomData::<M>,
     })
 }
@@ -57,6 +60,8 @@ struct BevyDetachedTaskRunner<I, O, Functor, M> {
     arc_output: Arc<Mutex<Option<O>>>,
     args: Option<(I, Functor)>,
     output: Output<O>,
+    token: CancellationToken,
+    cancellation_id: Option<CancellationId>,
     _m: PhantomData<M>,
 }
 
@@ -67,22 +72,42 @@ where
     Functor: AsyncFunctor<I, O, M> + Send + 'static,
     M: Send + 'static,
 {
-    fn run(&mut self, _: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+    fn run(&mut self, _: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
         if let Some((input, f)) = self.args.take() {
+            let cancellation_token = self.token.clone();
+            self.cancellation_id
+                .replace(token.register(move |_| cancellation_token.cancel()));
             let o = self.arc_output.clone();
+            let cancellation_token = self.token.clone();
+            let waker = token.waker();
             AsyncComputeTaskPool::get()
                 .spawn(async move {
-                    let out = f.functor(input).await;
-                    o.lock().unwrap().replace(out);
+                    let out = futures_lite::future::or(
+                        async { Some(f.functor(input, cancellation_token.clone()).await) },
+                        async {
+                            cancellation_token.cancelled().await;
+                            None
+                        },
+                    )
+                    .await;
+                    if let Some(out) = out {
+                        o.lock().unwrap().replace(out);
+                        if let Some(waker) = waker {
+                            waker.wake();
+                        }
+                    }
                 })
                 .detach();
         }
 
         if let Some(out) = self.arc_output.try_lock().ok().and_then(|mut o| o.take()) {
+            if let Some(id) = self.cancellation_id.take() {
+                token.unregister(&id);
+            }
             self.output.set(out);
             RunnerIs::Completed
         } else {
-            RunnerIs::Running
+            token.pending()
         }
     }
 }
@@ -90,13 +115,15 @@ where
 #[cfg(test)]
 mod tests {
     use crate::action::{once, side_effect};
-    use crate::prelude::{Pipe, Reactor};
+    use crate::prelude::{CancellationToken, Pipe, Reactor};
     use crate::tests::test_app;
+    use alloc::sync::Arc;
     use bevy::app::Startup;
     use bevy::platform::thread;
     use bevy::prelude::{Commands, Update};
     use bevy_test_helper::resource::count::Count;
     use bevy_test_helper::resource::DirectResourceControl;
+    use core::sync::atomic::{AtomicBool, Ordering};
     use core::time::Duration;
 
     #[test]
@@ -116,4 +143,32 @@ mod tests {
         app.update();
         app.assert_resource_eq(Count(2));
     }
+
+    #[test]
+    fn cancel_token_if_reactor_canceled() {
+        let mut app = test_app();
+        app.update();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let c = cancelled.clone();
+        let reactor = app
+            .world_mut()
+            .spawn(Reactor::schedule(move |task| async move {
+                task.will(
+                    Update,
+                    side_effect::bevy_task::spawn_detached(
+                        move |_: (), token: CancellationToken| async move {
+                            token.cancelled().await;
+                            c.store(true, Ordering::Relaxed);
+                        },
+                    ),
+                )
+                .await;
+            }))
+            .id();
+        app.update();
+        app.world_mut().despawn(reactor);
+        app.update();
+        thread::sleep(Duration::from_millis(20));
+        assert!(cancelled.load(Ordering::Relaxed));
+    }
 }
@@ -0,0 +1,93 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use bevy::platform::sync::Mutex;
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
+
+/// A handle used to cooperatively cancel a side effect.
+///
+/// The side effect actions pass this to the functions that accept it as an argument,
+/// and cancel it when the [`Reactor`](crate::prelude::Reactor) running them is canceled.
+/// Since threads and futures can't be stopped from the outside safely,
+/// the function should check [`CancellationToken::is_cancelled`] or await [`CancellationToken::cancelled`]
+/// to stop its work.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, side_effect::thread::spawn(|_: (), token: CancellationToken|{
+///         while !token.is_cancelled() {
+///             // Do the heavy work.
+///         }
+///     })).await;
+/// });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<Inner>);
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl CancellationToken {
+    /// Creates a new token that is not cancelled.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels this token and all of its clones.
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::Release);
+        if let Ok(mut wakers) = self.0.wakers.lock() {
+            for waker in wakers.drain(..) {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Returns true if this token has been cancelled.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Returns a future that completes when this token is cancelled.
+    #[inline]
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled(self.clone())
+    }
+}
+
+/// The future returned from [`CancellationToken::cancelled`].
+#[derive(Debug)]
+pub struct Cancelled(CancellationToken);
+
+impl Future for Cancelled {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.0.is_cancelled() {
+            return Poll::Ready(());
+        }
+        if let Ok(mut wakers) = self.0 .0.wakers.lock() {
+            if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+                wakers.push(cx.waker().clone());
+            }
+        }
+        // Check again in case `cancel` was called while registering the waker.
+        if self.0.is_cancelled() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,387 @@
+//! Channels to send values from any thread to the reactor.
+//!
+//! The receiving action sleeps until a value is sent,
+//! and then the sender wakes the reactor instead of the action being polled every frame.
+//!
+//! actions
+//!
+//! - [`side_effect::channel::oneshot`](crate::prelude::side_effect::channel::oneshot)
+//! - [`side_effect::channel::mpsc`](crate::prelude::side_effect::channel::mpsc)
+
+use crate::prelude::{ActionSeed, CancellationHandlers, Output, Runner, RunnerIs, RunnerWaker};
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use bevy::platform::sync::Mutex;
+use bevy::prelude::World;
+
+/// Creates a channel to send a single value, and the action waiting for it.
+///
+/// The value can be sent from any thread.
+/// The action sleeps until the value is sent, and completes with it.
+/// If the [`OneshotSender`] is dropped without sending, the [`Reactor`](crate::prelude::Reactor) is canceled.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let (tx, rx) = side_effect::channel::oneshot();
+///     std::thread::spawn(move ||{
+///         tx.send("Hello from the other thread").unwrap();
+///     });
+///     let message = task.will(Update, rx).await;
+///     assert_eq!(message, "Hello from the other thread");
+/// });
+/// ```
+pub fn oneshot<T>() -> (OneshotSender<T>, ActionSeed<(), T>)
+where
+    T: Send + 'static,
+{
+    let shared = Arc::new(Shared::new());
+    let receiver = ReceiverGuard(shared.clone());
+    let seed = ActionSeed::new(move |_, output| OneshotRunner { receiver, output });
+    (OneshotSender(Sender(shared)), seed)
+}
+
+/// Creates a channel to send multiple values.
+///
+/// The values can be sent from any thread through the cloneable [`Sender`],
+/// and are received in order by the actions created from [`Receiver::recv`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let (tx, rx) = side_effect::channel::mpsc();
+///     std::thread::spawn(move ||{
+///         for i in 0..3 {
+///             tx.send(i).unwrap();
+///         }
+///     });
+///     // Receive the values until all senders are dropped.
+///     while let Some(i) = task.will(Update, rx.recv()).await {
+///         println!("{i}");
+///     }
+/// });
+/// ```
+pub fn mpsc<T>() -> (Sender<T>, Receiver<T>)
+where
+    T: Send + 'static,
+{
+    let shared = Arc::new(Shared::new());
+    (Sender(shared.clone()), Receiver(ReceiverGuard(shared)))
+}
+
+/// The sending side of [`oneshot`].
+pub struct OneshotSender<T>(Sender<T>);
+
+impl<T> OneshotSender<T> {
+    /// Sends the value and wakes the reactor waiting for it.
+    ///
+    /// Returns the value as an error if the receiving action has been dropped.
+    #[inline]
+    pub fn send(self, value: T) -> Result<(), T> {
+        self.0.send(value)
+    }
+}
+
+/// The sending side of [`mpsc`].
+///
+/// This can be cloned to send from multiple threads.
+pub struct Sender<T>(Arc<Shared<T>>);
+
+impl<T> Sender<T> {
+    /// Sends the value and wakes the reactor waiting for it.
+    ///
+    /// Returns the value as an error if the [`Receiver`] has been dropped.
+    pub fn send(&self, value: T) -> Result<(), T> {
+        let waker = {
+            let Ok(mut state) = self.0.state.lock() else {
+                return Err(value);
+            };
+            if !state.receiver_alive {
+                return Err(value);
+            }
+            state.values.push_back(value);
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        Ok(())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        if let Ok(mut state) = self.0.state.lock() {
+            state.senders += 1;
+        }
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let Ok(mut state) = self.0.state.lock() else {
+                return;
+            };
+            state.senders -= 1;
+            if state.senders == 0 {
+                state.waker.take()
+            } else {
+                None
+            }
+        };
+        // Wake the reactor to notify that the channel has been closed.
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// The receiving side of [`mpsc`].
+pub struct Receiver<T>(ReceiverGuard<T>);
+
+impl<T> Receiver<T>
+where
+    T: Send + 'static,
+{
+    /// Returns the action that waits for the next value.
+    ///
+    /// The action sleeps until a value is sent.
+    /// Its output is `None` if all [`Sender`]s have been dropped and there are no values left.
+    pub fn recv(&self) -> ActionSeed<(), Option<T>> {
+        let shared = self.0 .0.clone();
+        ActionSeed::new(|_, output| RecvRunner { shared, output })
+    }
+
+    /// Takes the next value without waiting.
+    pub fn try_recv(&self) -> Option<T> {
+        self.0 .0.state.lock().ok()?.values.pop_front()
+    }
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+}
+
+struct State<T> {
+    values: VecDeque<T>,
+    waker: Option<RunnerWaker>,
+    senders: usize,
+    receiver_alive: bool,
+}
+
+impl<T> Shared<T> {
+    fn new() -> Self {
+        Self {
+            state: Mutex::new(State {
+                values: VecDeque::new(),
+                waker: None,
+                senders: 1,
+                receiver_alive: true,
+            }),
+        }
+    }
+
+    /// Takes the next value, or registers the waker to be woken when the value is sent.
+    ///
+    /// The output is `Some(None)` if the channel is closed.
+    fn poll_recv(&self, cancellation_handlers: &CancellationHandlers) -> Option<Option<T>> {
+        let Ok(mut state) = self.state.lock() else {
+            return Some(None);
+        };
+        if let Some(value) = state.values.pop_front() {
+            return Some(Some(value));
+        }
+        if state.senders == 0 {
+            return Some(None);
+        }
+        state.waker = cancellation_handlers.waker();
+        None
+    }
+}
+
+/// Closes the channel when the receiving side is dropped.
+struct ReceiverGuard<T>(Arc<Shared<T>>);
+
+impl<T> Drop for ReceiverGuard<T> {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.0.state.lock() {
+            state.receiver_alive = false;
+        }
+    }
+}
+
+struct OneshotRunner<T> {
+    receiver: ReceiverGuard<T>,
+    output: Output<T>,
+}
+
+impl<T> Runner for OneshotRunner<T> {
+    fn run(&mut self, _: &mut World, cancellation_handlers: &mut CancellationHandlers) -> RunnerIs {
+        match self.receiver.0.poll_recv(cancellation_handlers) {
+            Some(Some(value)) => {
+                self.output.set(value);
+                RunnerIs::Completed
+            }
+            Some(None) => RunnerIs::Canceled,
+            None => cancellation_handlers.pending(),
+        }
+    }
+}
+
+struct RecvRunner<T> {
+    shared: Arc<Shared<T>>,
+    output: Output<Option<T>>,
+}
+
+impl<T> Runner for RecvRunner<T> {
+    fn run(&mut self, _: &mut World, cancellation_handlers: &mut CancellationHandlers) -> RunnerIs {
+        if let Some(value) = self.shared.poll_recv(cancellation_handlers) {
+            self.output.set(value);
+            RunnerIs::Completed
+        } else {
+            cancellation_handlers.pending()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, side_effect};
+    use crate::prelude::*;
+    use crate::reactor::NativeReactor;
+    use crate::tests::test_app;
+    use bevy::app::{App, Update};
+    use bevy::prelude::{In, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    fn set_count() -> ActionSeed<usize> {
+        once::run(|In(num): In<usize>, mut count: ResMut<Count>| {
+            count.0 = num;
+        })
+    }
+
+    fn reactor_exists(app: &mut App) -> bool {
+        app.world_mut()
+            .query::<&NativeReactor>()
+            .iter(app.world())
+            .len()
+            == 1
+    }
+
+    #[test]
+    fn oneshot_receive() {
+        let mut app = test_app();
+        app.update();
+        let (tx, rx) = side_effect::channel::oneshot();
+        app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, rx.pipe(set_count())).await;
+        }));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        tx.send(3).unwrap();
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn oneshot_receive_value_sent_before_running() {
+        let mut app = test_app();
+        app.update();
+        let (tx, rx) = side_effect::channel::oneshot();
+        tx.send(3).unwrap();
+        app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, rx.pipe(set_count())).await;
+        }));
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn cancel_if_oneshot_sender_dropped() {
+        let mut app = test_app();
+        app.update();
+        let (tx, rx) = side_effect::channel::oneshot::<usize>();
+        app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, rx.pipe(set_count())).await;
+        }));
+        app.update();
+        assert!(reactor_exists(&mut app));
+        drop(tx);
+        app.update();
+        assert!(!reactor_exists(&mut app));
+    }
+
+    #[test]
+    fn err_if_receiver_dropped() {
+        let (tx, rx) = side_effect::channel::oneshot::<usize>();
+        drop(rx);
+        assert_eq!(tx.send(1), Err(1));
+
+        let (tx, rx) = side_effect::channel::mpsc::<usize>();
+        drop(rx);
+        assert_eq!(tx.send(1), Err(1));
+    }
+
+    #[test]
+    fn mpsc_receive_until_senders_dropped() {
+        let mut app = test_app();
+        app.update();
+        let (tx, rx) = side_effect::channel::mpsc::<usize>();
+        app.world_mut().spawn(Reactor::schedule(|task| async move {
+            while let Some(num) = task.will(Update, rx.recv()).await {
+                task.will(
+                    Update,
+                    once::run(move |mut count: ResMut<Count>| {
+                        count.0 += num;
+                    }),
+                )
+                .await;
+            }
+            task.will(Update, once::res::insert().with(Count(100)))
+                .await;
+        }));
+        app.update();
+        let tx2 = tx.clone();
+        tx.send(1).unwrap();
+        tx2.send(2).unwrap();
+        app.update();
+        app.assert_resource_eq(Count(3));
+        drop(tx);
+        app.update();
+        app.assert_resource_eq(Count(3));
+        drop(tx2);
+        app.update();
+        app.assert_resource_eq(Count(100));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn send_from_other_thread() {
+        let mut app = test_app();
+        app.update();
+        let (tx, rx) = side_effect::channel::oneshot();
+        app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, rx.pipe(set_count())).await;
+        }));
+        app.update();
+        std::thread::spawn(move || {
+            tx.send(5).unwrap();
+        })
+        .join()
+        .unwrap();
+        app.update();
+        app.assert_resource_eq(Count(5));
+    }
+}
@@ -0,0 +1,389 @@
+//! Convert the async [`Stream`] into [`Action`](crate::prelude::Action).
+//!
+//! The stream is polled on the main thread, and its items are processed with back-pressure:
+//! the next item is not polled until the current one has been processed,
+//! and at most the given number of items are processed per frame.
+//! While the stream has no items ready, the action sleeps until the stream wakes it.
+//!
+//! actions
+//!
+//! - [`side_effect::stream::for_each`](crate::prelude::side_effect::stream::for_each)
+//! - [`side_effect::stream::for_each_per_frame`](crate::prelude::side_effect::stream::for_each_per_frame)
+//! - [`side_effect::stream::collect`](crate::prelude::side_effect::stream::collect)
+//! - [`side_effect::stream::collect_per_frame`](crate::prelude::side_effect::stream::collect_per_frame)
+
+use crate::prelude::{ActionSeed, CancellationHandlers, Output, Runner, RunnerIs};
+use crate::runner::BoxedRunner;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use bevy::platform::cell::SyncCell;
+use bevy::prelude::World;
+use core::pin::Pin;
+use core::task::Poll;
+use futures_lite::{Stream, StreamExt};
+
+/// Runs the action created by `seed` for each item of the `stream` on the main thread,
+/// and then completes when the stream ends.
+///
+/// One item is processed per frame; use [`for_each_per_frame`] to change it.
+/// The next item is not polled until the action of the current item is completed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Progress(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     let progress = futures_lite::stream::iter([25, 50, 75, 100]);
+///     task.will(Update, side_effect::stream::for_each(progress, || {
+///         once::run(|In(percent): In<usize>, mut progress: ResMut<Progress>|{
+///             progress.0 = percent;
+///         })
+///     })).await;
+/// });
+/// ```
+#[inline]
+pub fn for_each<S, Seed>(stream: S, seed: Seed) -> ActionSeed
+where
+    S: Stream + Send + 'static,
+    S::Item: 'static,
+    Seed: Fn() -> ActionSeed<S::Item, ()> + Send + Sync + 'static,
+{
+    for_each_per_frame(1, stream, seed)
+}
+
+/// Runs the action created by `seed` for each item of the `stream` on the main thread,
+/// and then completes when the stream ends.
+///
+/// At most `items_per_frame` items are processed per frame.
+/// The next item is not polled until the action of the current item is completed.
+///
+/// # Panics
+///
+/// Panics if `items_per_frame` is zero.
+pub fn for_each_per_frame<S, Seed>(items_per_frame: usize, stream: S, seed: Seed) -> ActionSeed
+where
+    S: Stream + Send + 'static,
+    S::Item: 'static,
+    Seed: Fn() -> ActionSeed<S::Item, ()> + Send + Sync + 'static,
+{
+    assert_ne!(
+        items_per_frame, 0,
+        "`items_per_frame` must be greater than 0"
+    );
+    let stream = SyncCell::new(stream);
+    ActionSeed::new(move |_, output| StreamRunner {
+        stream: Box::pin(SyncCell::to_inner(stream)),
+        items_per_frame,
+        output,
+        consumer: ForEach { seed, runner: None },
+    })
+}
+
+/// Collects the items of the `stream` into [`Vec`].
+///
+/// One item is collected per frame; use [`collect_per_frame`] to change it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let items: Vec<usize> = task.will(Update, side_effect::stream::collect(
+///         futures_lite::stream::iter([1, 2, 3])
+///     )).await;
+///     assert_eq!(items, vec![1, 2, 3]);
+/// });
+/// ```
+#[inline]
+pub fn collect<S>(stream: S) -> ActionSeed<(), Vec<S::Item>>
+where
+    S: Stream + Send + 'static,
+    S::Item: 'static,
+{
+    collect_per_frame(1, stream)
+}
+
+/// Collects the items of the `stream` into [`Vec`].
+///
+/// At most `items_per_frame` items are collected per frame.
+///
+/// # Panics
+///
+/// Panics if `items_per_frame` is zero.
+pub fn collect_per_frame<S>(items_per_frame: usize, stream: S) -> ActionSeed<(), Vec<S::Item>>
+where
+    S: Stream + Send + 'static,
+    S::Item: 'static,
+{
+    assert_ne!(
+        items_per_frame, 0,
+        "`items_per_frame` must be greater than 0"
+    );
+    let stream = SyncCell::new(stream);
+    ActionSeed::new(move |_, output| StreamRunner {
+        stream: Box::pin(SyncCell::to_inner(stream)),
+        items_per_frame,
+        output,
+        consumer: Collect(Vec::new()),
+    })
+}
+
+trait Consumer<Item> {
+    type Output;
+
+    /// Starts processing the item.
+    fn start(&mut self, item: Item);
+
+    /// Processes the current item; the next item is started after this returns [`RunnerIs::Completed`].
+    fn run(
+        &mut self,
+        world: &mut World,
+        cancellation_handlers: &mut CancellationHandlers,
+    ) -> RunnerIs;
+
+    fn finish(&mut self) -> Self::Output;
+}
+
+struct ForEach<Seed> {
+    seed: Seed,
+    runner: Option<BoxedRunner>,
+}
+
+impl<Item, Seed> Consumer<Item> for ForEach<Seed>
+where
+    Item: 'static,
+    Seed: Fn() -> ActionSeed<Item, ()>,
+{
+    type Output = ();
+
+    fn start(&mut self, item: Item) {
+        self.runner
+            .replace((self.seed)().create_runner(item, Output::default()));
+    }
+
+    fn run(
+        &mut self,
+        world: &mut World,
+        cancellation_handlers: &mut CancellationHandlers,
+    ) -> RunnerIs {
+        let Some(runner) = self.runner.as_mut() else {
+            return RunnerIs::Completed;
+        };
+        let status = runner.run(world, cancellation_handlers);
+        if status.is_completed() {
+            self.runner = None;
+        }
+        status
+    }
+
+    #[inline]
+    fn finish(&mut self) {}
+}
+
+struct Collect<Item>(Vec<Item>);
+
+impl<Item> Consumer<Item> for Collect<Item> {
+    type Output = Vec<Item>;
+
+    #[inline]
+    fn start(&mut self, item: Item) {
+        self.0.push(item);
+    }
+
+    #[inline]
+    fn run(&mut self, _: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+        RunnerIs::Completed
+    }
+
+    #[inline]
+    fn finish(&mut self) -> Vec<Item> {
+        core::mem::take(&mut self.0)
+    }
+}
+
+struct StreamRunner<S, C: Consumer<S::Item>>
+where
+    S: Stream,
+{
+    stream: Pin<Box<S>>,
+    items_per_frame: usize,
+    output: Output<C::Output>,
+    consumer: C,
+}
+
+impl<S, C> Runner for StreamRunner<S, C>
+where
+    S: Stream,
+    C: Consumer<S::Item>,
+{
+    fn run(
+        &mut self,
+        world: &mut World,
+        cancellation_handlers: &mut CancellationHandlers,
+    ) -> RunnerIs {
+        let mut started = 0;
+        loop {
+            match self.consumer.run(world, cancellation_handlers) {
+                RunnerIs::Completed => {}
+                other => return other,
+            }
+            if started == self.items_per_frame {
+                return RunnerIs::Running;
+            }
+            match cancellation_handlers.poll(&mut self.stream.next()) {
+                Poll::Ready(Some(item)) => {
+                    started += 1;
+                    self.consumer.start(item);
+                }
+                Poll::Ready(None) => {
+                    self.output.set(self.consumer.finish());
+                    return RunnerIs::Completed;
+                }
+                Poll::Pending => return cancellation_handlers.pending(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, side_effect};
+    use crate::prelude::*;
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    fn add_count() -> ActionSeed<usize> {
+        once::run(|In(num): In<usize>, mut count: ResMut<Count>| {
+            count.0 += num;
+        })
+    }
+
+    #[test]
+    fn for_each_one_item_per_frame() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    side_effect::stream::for_each(futures_lite::stream::iter([1, 2, 3]), add_count),
+                )
+                .await;
+                task.will(Update, once::res::insert().with(Count(100)))
+                    .await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(3));
+        app.update();
+        app.assert_resource_eq(Count(6));
+        app.update();
+        app.assert_resource_eq(Count(100));
+    }
+
+    #[test]
+    fn for_each_per_frame() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    side_effect::stream::for_each_per_frame(
+                        2,
+                        futures_lite::stream::iter([1, 2, 3]),
+                        add_count,
+                    ),
+                )
+                .await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(3));
+        app.update();
+        app.assert_resource_eq(Count(6));
+    }
+
+    #[test]
+    fn wait_for_item_action_before_polling_next() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    side_effect::stream::for_each_per_frame(
+                        10,
+                        futures_lite::stream::iter([1, 2]),
+                        || {
+                            ActionSeed::define(|num: usize| {
+                                delay::frames().with(1).then(add_count().with(num))
+                            })
+                        },
+                    ),
+                )
+                .await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn collect_items() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let items = task
+                    .will(
+                        Update,
+                        side_effect::stream::collect_per_frame(
+                            2,
+                            futures_lite::stream::iter([1, 2, 3]),
+                        ),
+                    )
+                    .await;
+                task.will(Update, once::res::insert().with(Count(items.iter().sum())))
+                    .await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(6));
+    }
+
+    #[test]
+    fn sleep_while_stream_is_pending() {
+        let mut app = test_app();
+        app.update();
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, side_effect::stream::for_each(rx, add_count))
+                .await;
+            task.will(Update, once::res::insert().with(Count(100)))
+                .await;
+        }));
+        app.update();
+        app.assert_resource_eq(Count(0));
+        tx.unbounded_send(5).unwrap();
+        app.update();
+        app.assert_resource_eq(Count(5));
+        drop(tx);
+        app.update();
+        app.assert_resource_eq(Count(100));
+    }
+}
@@ -4,8 +4,8 @@
 //!
 //! - [`side_effect::thread::spawn`](crate::prelude::side_effect::thread::spawn)
 
-use crate::prelude::side_effect::Functor;
-use crate::prelude::{ActionSeed, CancellationHandlers, RunnerIs};
+use crate::prelude::side_effect::{CancellationToken, Functor};
+use crate::prelude::{ActionSeed, CancellationHandlers, CancellationId, RunnerIs};
 use crate::runner::{Output, Runner};
 use alloc::sync::Arc;
 use bevy::platform::sync::Mutex;
@@ -15,7 +15,9 @@ use bevy::prelude::*;
 ///
 /// The thread is started when [`Runner`] is executed for the first time.
 ///
-/// Note that thead created from this function will continue to run even if [`Reactor`](crate::prelude::Reactor) is canceled.
+/// Threads can't be stopped from the outside, so the thread continues to run even if [`Reactor`](crate::prelude::Reactor) is canceled.
+/// To stop the work cooperatively, accept [`CancellationToken`] as the second argument of the function;
+/// it is cancelled when the [`Reactor`](crate::prelude::Reactor) is canceled.
 ///
 /// # Examples
 ///
@@ -37,17 +39,30 @@ use bevy::prelude::*;
 ///             }))
 ///     }).await;
 /// });
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, side_effect::thread::spawn(|_: (), token: CancellationToken|{
+///         while !token.is_cancelled() {
+///             // Do the heavy work.
+///         }
+///     })).await;
+/// });
 /// ```
 pub fn spawn<I, O, M>(f: impl Functor<I, O, M> + Send + Sync + 'static) -> ActionSeed<I, O>
 where
     I: Send + 'static,
     O: Send + 'static,
 {
-    ActionSeed::new(|input, output: Output<O>| ThreadRunner {
-        arc_output: Arc::new(Mutex::new(None)),
-        args: Some(f.functor(input)),
-        output,
-        handle: None,
+    ActionSeed::new(|input, output: Output<O>| {
+        let token = CancellationToken::new();
+        ThreadRunner {
+            arc_output: Arc::new(Mutex::new(None)),
+            args: Some(f.functor(input, token.clone())),
+            output,
+            handle: None,
+            token,
+            cancellation_id: None,
+        }
     })
 }
 
@@ -56,6 +71,8 @@ struct ThreadRunner<O, F> {
     args: Option<F>,
     output: Output<O>,
     handle: Option<std::thread::JoinHandle<()>>,
+    token: CancellationToken,
+    cancellation_id: Option<CancellationId>,
 }
 
 impl<O, F> Runner for ThreadRunner<O, F>
@@ -63,19 +80,30 @@ where
     O: Send + 'static,
     F: FnOnce() -> O + Send + 'static,
 {
-    fn run(&mut self, _: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+    fn run(&mut self, _: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
         if let Some(f) = self.args.take() {
+            let cancellation_token = self.token.clone();
+            self.cancellation_id
+                .replace(token.register(move |_| cancellation_token.cancel()));
             let arc_out = self.arc_output.clone();
+            let waker = token.waker();
             self.handle.replace(std::thread::spawn(move || {
-                arc_out.lock().unwrap().replace(f());
+                let out = f();
+                arc_out.lock().unwrap().replace(out);
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
             }));
         }
 
         if let Some(out) = self.arc_output.try_lock().ok().and_then(|mut o| o.take()) {
+            if let Some(id) = self.cancellation_id.take() {
+                token.unregister(&id);
+            }
             self.output.set(out);
             RunnerIs::Completed
         } else {
-            RunnerIs::Running
+            token.pending()
         }
     }
 }
@@ -83,12 +111,14 @@ where
 #[cfg(test)]
 mod tests {
     use crate::action::{once, side_effect};
-    use crate::prelude::{Pipe, Reactor};
+    use crate::prelude::{CancellationToken, Pipe, Reactor};
     use crate::tests::test_app;
+    use alloc::sync::Arc;
     use bevy::platform::thread;
     use bevy::prelude::*;
     use bevy_test_helper::resource::count::Count;
     use bevy_test_helper::resource::DirectResourceControl;
+    use core::sync::atomic::{AtomicBool, Ordering};
 
     #[test]
     fn thread_calc_2() {
@@ -131,4 +161,35 @@ mod tests {
         app.update();
         app.assert_resource_eq(Count(2));
     }
+
+    #[test]
+    fn cancel_token_if_reactor_canceled() {
+        let mut app = test_app();
+        app.update();
+        let stopped = Arc::new(AtomicBool::new(false));
+        let s = stopped.clone();
+        let reactor = app
+            .world_mut()
+            .spawn(Reactor::schedule(move |task| async move {
+                task.will(
+                    Update,
+                    side_effect::thread::spawn(move |_: (), token: CancellationToken| {
+                        while !token.is_cancelled() {
+                            thread::sleep(core::time::Duration::from_millis(1));
+                        }
+                        s.store(true, Ordering::Relaxed);
+                    }),
+                )
+                .await;
+            }))
+            .id();
+        app.update();
+        thread::sleep(core::time::Duration::from_millis(10));
+        assert!(!stopped.load(Ordering::Relaxed));
+
+        app.world_mut().despawn(reactor);
+        app.update();
+        thread::sleep(core::time::Duration::from_millis(20));
+        assert!(stopped.load(Ordering::Relaxed));
+    }
 }
@@ -3,19 +3,31 @@
 //! action
 //!
 //! - [`side_effect::tokio::spawn`](crate::prelude::side_effect::tokio::spawn)
+//!
+//! All tasks are spawned onto the runtime shared through [`FlurxTokioRuntime`],
+//! which can be configured with [`FlurxTokioPlugin`].
+
+pub use runtime::{FlurxTokioPlugin, FlurxTokioRuntime};
 
-use crate::action::side_effect::AsyncFunctor;
-use crate::prelude::{ActionSeed, CancellationHandlers, RunnerIs};
+use crate::action::side_effect::{AsyncFunctor, CancellationToken};
+use crate::prelude::{ActionSeed, CancellationHandlers, CancellationId, RunnerIs};
 use crate::runner::{Output, Runner};
 use alloc::sync::Arc;
+use bevy::log::error;
 use bevy::prelude::World;
 use core::marker::PhantomData;
-use tokio::runtime::Runtime;
 use tokio::task::JoinHandle;
 
+mod runtime;
+
 /// Spawns a new tokio task, and then wait its output.
 ///
 /// The task is started when [`Runner`] is executed for the first time.
+/// It is spawned onto the runtime of [`FlurxTokioRuntime`]; if the resource doesn't exist,
+/// a multi-threaded runtime is built and inserted. If building it fails, the [`Reactor`](crate::prelude::Reactor) is canceled.
+///
+/// The task is aborted when [`Reactor`](crate::prelude::Reactor) is canceled.
+/// If the function accepts [`CancellationToken`] as the second argument, it is also cancelled at that time.
 ///
 /// # Example
 ///
@@ -47,18 +59,20 @@ where
         arc_output: Arc::new(tokio::sync::Mutex::new(None)),
         args: Some((input, f)),
         output,
-        rt: Runtime::new().unwrap(),
         handle: None,
+        token: CancellationToken::new(),
+        cancellation_id: None,
         _m: PhantomData,
     })
 }
 
 struct TokioRunner<I, Out, Functor, M> {
     args: Option<(I, Functor)>,
-    rt: Runtime,
     arc_output: Arc<tokio::sync::Mutex<Option<Out>>>,
     output: Output<Out>,
     handle: Option<JoinHandle<()>>,
+    token: CancellationToken,
+    cancellation_id: Option<CancellationId>,
     _m: PhantomData<M>,
 }
 
@@ -70,26 +84,59 @@ where
     Out: Send + 'static,
 {
     #[allow(clippy::async_yields_async)]
-    fn run(&mut self, _: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
         if let Some((input, functor)) = self.args.take() {
+            let Some(rt) = shared_runtime(world) else {
+                return RunnerIs::Canceled;
+            };
             let arc_output = self.arc_output.clone();
-            self.handle.replace(self.rt.spawn(async move {
-                arc_output
-                    .lock()
-                    .await
-                    .replace(functor.functor(input).await);
+            let cancellation_token = self.token.clone();
+            let waker = token.waker();
+            let handle = rt.handle().spawn(async move {
+                let out = functor.functor(input, cancellation_token).await;
+                arc_output.lock().await.replace(out);
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            });
+            let abort_handle = handle.abort_handle();
+            let cancellation_token = self.token.clone();
+            self.cancellation_id.replace(token.register(move |_| {
+                cancellation_token.cancel();
+                abort_handle.abort();
             }));
+            self.handle.replace(handle);
         }
 
         if let Ok(mut out) = self.arc_output.try_lock() {
             if let Some(out) = out.take() {
+                if let Some(id) = self.cancellation_id.take() {
+                    token.unregister(&id);
+                }
                 self.output.set(out);
                 RunnerIs::Completed
             } else {
-                RunnerIs::Running
+                token.pending()
             }
         } else {
-            RunnerIs::Running
+            // The task is writing the output; it wakes the reactor after that.
+            token.pending()
+        }
+    }
+}
+
+fn shared_runtime(world: &mut World) -> Option<FlurxTokioRuntime> {
+    if let Some(rt) = world.get_resource::<FlurxTokioRuntime>() {
+        return Some(rt.clone());
+    }
+    match FlurxTokioRuntime::multi_thread(None) {
+        Ok(rt) => {
+            world.insert_resource(rt.clone());
+            Some(rt)
+        }
+        Err(e) => {
+            error!("Failed to build the tokio runtime, so the reactor is canceled: {e}");
+            None
         }
     }
 }
@@ -0,0 +1,287 @@
+use alloc::sync::Arc;
+use bevy::app::{App, First, Plugin};
+use bevy::prelude::{Res, Resource};
+use tokio::runtime::{Builder, Handle, Runtime};
+
+/// The tokio runtime shared by all [`side_effect::tokio`](crate::prelude::side_effect::tokio) actions.
+///
+/// This is inserted by [`FlurxTokioPlugin`].
+/// If it doesn't exist when the tokio action is executed for the first time,
+/// a multi-threaded runtime with the default settings is built and inserted.
+/// If it can't be built, the error is logged and the reactor running the action is canceled.
+///
+/// You can also build it yourself to handle the error of building the runtime.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// let runtime = FlurxTokioRuntime::multi_thread(Some(2)).expect("Failed to build the tokio runtime");
+/// App::new()
+///     .add_plugins((MinimalPlugins, FlurxPlugin))
+///     .insert_resource(runtime);
+/// ```
+#[derive(Resource, Clone, Debug)]
+pub struct FlurxTokioRuntime {
+    handle: Handle,
+    runtime: Option<Arc<Runtime>>,
+}
+
+impl FlurxTokioRuntime {
+    /// Builds a new multi-threaded runtime.
+    ///
+    /// If `worker_threads` is `None`, the number of the cores is used.
+    pub fn multi_thread(worker_threads: Option<usize>) -> tokio::io::Result<Self> {
+        let mut builder = Builder::new_multi_thread();
+        if let Some(worker_threads) = worker_threads {
+            builder.worker_threads(worker_threads);
+        }
+        Ok(Self::from_runtime(builder.enable_all().build()?))
+    }
+
+    /// Builds a new current-thread runtime.
+    ///
+    /// The runtime doesn't spawn any worker threads; instead, [`FlurxTokioPlugin`] drives it once per frame
+    /// in the [`First`] schedule.
+    /// Note that the tasks don't make progress if the plugin is not added,
+    /// or if the app is run within another tokio runtime, which can't drive this one.
+    pub fn current_thread() -> tokio::io::Result<Self> {
+        Ok(Self::from_runtime(
+            Builder::new_current_thread().enable_all().build()?,
+        ))
+    }
+
+    /// Uses the runtime that the `handle` points to.
+    ///
+    /// This is useful if the application already has the tokio runtime.
+    /// The runtime isn't driven by this crate, so it must be kept alive and driven by its owner.
+    #[inline]
+    pub const fn from_handle(handle: Handle) -> Self {
+        Self {
+            handle,
+            runtime: None,
+        }
+    }
+
+    /// Returns the handle of the runtime.
+    #[inline]
+    pub const fn handle(&self) -> &Handle {
+        &self.handle
+    }
+
+    fn from_runtime(runtime: Runtime) -> Self {
+        Self {
+            handle: runtime.handle().clone(),
+            runtime: Some(Arc::new(runtime)),
+        }
+    }
+
+    fn drive(&self) {
+        // `block_on` panics if it is called within a tokio runtime,
+        // such as when the app itself is run by `#[tokio::main]`.
+        if Handle::try_current().is_ok() {
+            return;
+        }
+        if let Some(runtime) = self.runtime.as_ref() {
+            // Runs the tasks that are ready and polls the drivers once.
+            runtime.block_on(tokio::task::yield_now());
+        }
+    }
+
+    fn is_current_thread(&self) -> bool {
+        self.runtime.is_some()
+            && matches!(
+                self.handle.runtime_flavor(),
+                tokio::runtime::RuntimeFlavor::CurrentThread
+            )
+    }
+}
+
+/// Configures the [`FlurxTokioRuntime`] shared by the tokio actions.
+///
+/// By default, a multi-threaded runtime with the number of the cores as worker threads is used.
+///
+/// # Panics
+///
+/// Panics when the plugin is built if the runtime can't be built.
+/// Insert [`FlurxTokioRuntime`] yourself if you want to handle the error.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// App::new()
+///     .add_plugins((
+///         MinimalPlugins,
+///         FlurxPlugin,
+///         FlurxTokioPlugin::default().worker_threads(2),
+///     ));
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct FlurxTokioPlugin {
+    kind: RuntimeKind,
+}
+
+#[derive(Default, Debug, Clone)]
+enum RuntimeKind {
+    #[default]
+    MultiThread,
+    WorkerThreads(usize),
+    CurrentThread,
+    Handle(Handle),
+}
+
+impl FlurxTokioPlugin {
+    /// Uses a multi-threaded runtime with the given number of worker threads.
+    #[inline]
+    pub fn worker_threads(mut self, worker_threads: usize) -> Self {
+        self.kind = RuntimeKind::WorkerThreads(worker_threads);
+        self
+    }
+
+    /// Uses a current-thread runtime driven once per frame.
+    ///
+    /// See [`FlurxTokioRuntime::current_thread`] for details.
+    #[inline]
+    pub fn current_thread(mut self) -> Self {
+        self.kind = RuntimeKind::CurrentThread;
+        self
+    }
+
+    /// Uses the externally provided runtime.
+    ///
+    /// See [`FlurxTokioRuntime::from_handle`] for details.
+    #[inline]
+    pub fn handle(mut self, handle: Handle) -> Self {
+        self.kind = RuntimeKind::Handle(handle);
+        self
+    }
+}
+
+impl Plugin for FlurxTokioPlugin {
+    fn build(&self, app: &mut App) {
+        let runtime = match &self.kind {
+            RuntimeKind::MultiThread => FlurxTokioRuntime::multi_thread(None),
+            RuntimeKind::WorkerThreads(worker_threads) => {
+                FlurxTokioRuntime::multi_thread(Some(*worker_threads))
+            }
+            RuntimeKind::CurrentThread => FlurxTokioRuntime::current_thread(),
+            RuntimeKind::Handle(handle) => Ok(FlurxTokioRuntime::from_handle(handle.clone())),
+        };
+        app.insert_resource(runtime.expect("Failed to build the tokio runtime"))
+            .add_systems(First, drive_current_thread_runtime);
+    }
+}
+
+fn drive_current_thread_runtime(runtime: Option<Res<FlurxTokioRuntime>>) {
+    if let Some(runtime) = runtime.filter(|runtime| runtime.is_current_thread()) {
+        runtime.drive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, side_effect};
+    use crate::prelude::{FlurxTokioPlugin, FlurxTokioRuntime, Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{App, Startup};
+    use bevy::platform::thread;
+    use bevy::prelude::{Commands, In, ResMut, Update};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use core::time::Duration;
+
+    fn spawn_sleep_reactor(mut commands: Commands) {
+        commands.spawn(Reactor::schedule(|task| async move {
+            task.will(
+                Update,
+                side_effect::tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                    3
+                })
+                .pipe(once::run(
+                    |In(num): In<usize>, mut count: ResMut<Count>| {
+                        count.0 = num;
+                    },
+                )),
+            )
+            .await;
+        }));
+    }
+
+    fn update_until_count(app: &mut App, expect: usize) {
+        for _ in 0..100 {
+            app.update();
+            if app.world().resource::<Count>().0 == expect {
+                return;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        app.assert_resource_eq(Count(expect));
+    }
+
+    #[test]
+    fn insert_default_runtime_if_not_exists() {
+        let mut app = test_app();
+        app.add_systems(Startup, spawn_sleep_reactor);
+        update_until_count(&mut app, 3);
+        assert!(app.world().contains_resource::<FlurxTokioRuntime>());
+    }
+
+    #[test]
+    fn share_runtime() {
+        let mut app = test_app();
+        app.add_plugins(FlurxTokioPlugin::default().worker_threads(1));
+        let handle = app.world().resource::<FlurxTokioRuntime>().handle().clone();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let id = task
+                    .will(
+                        Update,
+                        side_effect::tokio::spawn(
+                            async move { tokio::runtime::Handle::current().id() },
+                        ),
+                    )
+                    .await;
+                task.will(Update, once::res::insert().with(RuntimeId(id)))
+                    .await;
+            }));
+        });
+        for _ in 0..100 {
+            app.update();
+            if app.world().contains_resource::<RuntimeId>() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(app.world().resource::<RuntimeId>().0, handle.id());
+    }
+
+    #[test]
+    fn current_thread_runtime() {
+        let mut app = test_app();
+        app.add_plugins(FlurxTokioPlugin::default().current_thread());
+        app.add_systems(Startup, spawn_sleep_reactor);
+        update_until_count(&mut app, 3);
+    }
+
+    #[test]
+    fn external_handle() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .unwrap();
+        let mut app = test_app();
+        app.add_plugins(FlurxTokioPlugin::default().handle(runtime.handle().clone()));
+        app.add_systems(Startup, spawn_sleep_reactor);
+        update_until_count(&mut app, 3);
+    }
+
+    #[derive(bevy::prelude::Resource)]
+    struct RuntimeId(tokio::runtime::Id);
+}
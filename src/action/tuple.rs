@@ -31,12 +31,12 @@ impl<O> Runner for TupleRunner<O> {
         world: &mut World,
         token: &mut CancellationHandlers,
     ) -> crate::prelude::RunnerIs {
-        self.runner.run(world, token);
+        let runner_is = self.runner.run(world, token);
         if let Some(o) = self.tmp.take() {
             self.output.set((o,));
             RunnerIs::Completed
         } else {
-            RunnerIs::Running
+            RunnerIs::pending(runner_is.is_sleeping())
         }
     }
 }
@@ -3,11 +3,13 @@
 use crate::action::seed::ActionSeed;
 use crate::prelude::{wait, RunnerIs};
 use crate::runner::{CancellationHandlers, Output, Runner};
-pub use _any::any;
+pub use _any::{any, any_output};
 pub use _both::both;
 pub use _either::*;
-pub use all::{all, private};
+pub use all::{all, all_outputs, private};
+pub use at_least::{at_least, first_n};
 use bevy::prelude::{In, IntoSystem, System, SystemIn, SystemInput, World};
+pub use rate::{audit, debounce, sample, throttle};
 
 #[path = "wait/any.rs"]
 mod _any;
@@ -16,12 +18,16 @@ mod _both;
 #[path = "wait/either.rs"]
 mod _either;
 mod all;
+mod at_least;
 #[cfg(feature = "audio")]
 #[cfg_attr(docsrs, doc(cfg(feature = "audio")))]
 pub mod audio;
 pub mod event;
 pub mod input;
 pub mod message;
+pub mod race;
+mod rate;
+pub mod read_only;
 #[cfg(feature = "state")]
 #[cfg_attr(docsrs, doc(cfg(feature = "state")))]
 pub mod state;
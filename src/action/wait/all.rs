@@ -1,3 +1,4 @@
+use crate::action::Action;
 use crate::prelude::{ActionSeed, Output, Runner};
 use crate::runner::{BoxedRunner, CancellationHandlers, RunnerIs};
 use bevy::prelude::*;
@@ -44,11 +45,13 @@ struct AllRunner {
 impl Runner for AllRunner {
     fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
         let runners = core::mem::take(&mut self.runners);
+        let mut all_sleeping = true;
         for mut runner in runners {
             match runner.run(world, token) {
                 RunnerIs::Canceled => return RunnerIs::Canceled,
                 RunnerIs::Completed => {}
-                RunnerIs::Running => {
+                runner_is => {
+                    all_sleeping &= runner_is.is_sleeping();
                     self.runners.push(runner);
                 }
             }
@@ -57,7 +60,84 @@ impl Runner for AllRunner {
             self.output.set(());
             RunnerIs::Completed
         } else {
-            RunnerIs::Running
+            RunnerIs::pending(all_sleeping)
+        }
+    }
+}
+
+/// Wait until all the actions are completed, and output their outputs in the order of the actions.
+///
+/// Unlike [`wait::all`](crate::prelude::wait::all()), the actions can have outputs,
+/// and unlike [`wait_all!`](crate::wait_all), the number of the actions can be decided at runtime.
+/// If the inputs of the actions differ, convert them with [`OmitInput::omit_input`](crate::prelude::OmitInput::omit_input).
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let frames: Vec<usize> = task.will(Update, wait::all_outputs().with(
+///         (1..=3)
+///             .map(|frames| delay::frames().with(frames).map(move |_| frames))
+///             .collect()
+///     )).await;
+///     assert_eq!(frames, vec![1, 2, 3]);
+/// });
+/// ```
+pub fn all_outputs<I, O>() -> ActionSeed<Vec<Action<I, O>>, Vec<O>>
+where
+    I: 'static,
+    O: 'static,
+{
+    ActionSeed::new(|actions: Vec<Action<I, O>>, output| {
+        let (runners, outputs) = actions
+            .into_iter()
+            .map(|action| {
+                let o = Output::default();
+                (Some(action.create_runner(o.clone())), o)
+            })
+            .unzip();
+        AllOutputsRunner {
+            runners,
+            outputs,
+            output,
+        }
+    })
+}
+
+struct AllOutputsRunner<O> {
+    runners: Vec<Option<BoxedRunner>>,
+    outputs: Vec<Output<O>>,
+    output: Output<Vec<O>>,
+}
+
+impl<O> Runner for AllOutputsRunner<O> {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let mut all_sleeping = true;
+        for slot in &mut self.runners {
+            let Some(runner) = slot else {
+                continue;
+            };
+            match runner.run(world, token) {
+                RunnerIs::Canceled => return RunnerIs::Canceled,
+                RunnerIs::Completed => {
+                    slot.take();
+                }
+                runner_is => all_sleeping &= runner_is.is_sleeping(),
+            }
+        }
+        if self.runners.iter().all(Option::is_none) {
+            self.output.set(
+                self.outputs
+                    .iter()
+                    .map(|o| o.take().expect("The output value has not been set!!!"))
+                    .collect(),
+            );
+            RunnerIs::Completed
+        } else {
+            RunnerIs::pending(all_sleeping)
         }
     }
 }
@@ -177,16 +257,17 @@ pub mod private {
             {
                 #[allow(non_snake_case)]
                   fn run(&mut self, world: &mut bevy::prelude::World, token: &mut $crate::prelude::CancellationHandlers) -> RunnerIs {
+                    let mut all_sleeping = true;
                     if self.o1.is_none(){
                         match self.r1.run(world, token){
                             RunnerIs::Canceled => return RunnerIs::Canceled,
-                            _ => {}
+                            runner_is => all_sleeping &= !matches!(runner_is, RunnerIs::Running),
                         }
                     }
                     if self.o2.is_none(){
                         match self.r2.run(world, token){
                             RunnerIs::Canceled => return RunnerIs::Canceled,
-                            _ => {}
+                            runner_is => all_sleeping &= !matches!(runner_is, RunnerIs::Running),
                         }
                     }
                     if let Some(($($lhs_out,)*)) = self.o1.take(){
@@ -195,10 +276,10 @@ pub mod private {
                             RunnerIs::Completed
                         }else{
                             self.o1.set(($($lhs_out,)*));
-                            RunnerIs::Running
+                            RunnerIs::pending(all_sleeping)
                         }
                     }else{
-                        RunnerIs::Running
+                        RunnerIs::pending(all_sleeping)
                     }
                 }
             }
@@ -212,7 +293,7 @@ pub mod private {
 mod tests {
     use crate::action::delay;
     use crate::actions;
-    use crate::prelude::{once, wait, Pipe, Then};
+    use crate::prelude::{once, wait, Map, Pipe, Then};
     use crate::reactor::Reactor;
     use crate::tests::{decrement_count, exit_reader, increment_count, test_app};
     use bevy::app::{AppExit, Startup, Update};
@@ -329,4 +410,31 @@ mod tests {
         app.update();
         assert!(app.world().get_non_send_resource::<AppExit>().is_some());
     }
+
+    #[test]
+    fn all_outputs_in_order() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let outputs = task
+                    .will(
+                        Update,
+                        wait::all_outputs().with(alloc::vec![
+                            delay::frames().with(2).map(|_| 1),
+                            delay::frames().with(0).map(|_| 2),
+                            delay::frames().with(1).map(|_| 3),
+                        ]),
+                    )
+                    .await;
+                assert_eq!(outputs, alloc::vec![1, 2, 3]);
+                task.will(Update, once::message::app_exit_success()).await;
+            }));
+        });
+        let mut er = exit_reader();
+        app.update();
+        app.update();
+        app.assert_message_not_comes(&mut er);
+        app.update();
+        app.assert_message_comes(&mut er);
+    }
 }
@@ -1,3 +1,5 @@
+use crate::action::wait::race::RaceRunner;
+use crate::action::Action;
 use crate::prelude::ActionSeed;
 use crate::runner::{BoxedRunner, CancellationHandlers, Output, Runner, RunnerIs};
 use bevy::prelude::*;
@@ -51,6 +53,7 @@ struct AnyRunner {
 impl Runner for AnyRunner {
     fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
         let mut finished = None;
+        let mut all_sleeping = true;
         for (i, runner) in self.runners.iter_mut().enumerate() {
             match runner.run(world, token) {
                 RunnerIs::Completed => {
@@ -60,7 +63,8 @@ impl Runner for AnyRunner {
                 RunnerIs::Canceled => {
                     return RunnerIs::Canceled;
                 }
-                RunnerIs::Running => continue,
+                RunnerIs::Running => all_sleeping = false,
+                RunnerIs::Sleeping => continue,
             }
         }
         if let Some(finished_index) = finished {
@@ -68,20 +72,64 @@ impl Runner for AnyRunner {
             self.output.set(finished_index);
             RunnerIs::Completed
         } else {
-            RunnerIs::Running
+            RunnerIs::pending(all_sleeping)
         }
     }
 }
 
+/// Wait until the execution of one of the actions is completed,
+/// and output the index and the output of the completed action.
+///
+/// When an action completes, the remaining actions are canceled:
+/// the cancellation handlers they registered are called, and they are never run again.
+/// If the inputs of the actions differ, convert them with [`OmitInput::omit_input`](crate::prelude::OmitInput::omit_input).
+///
+/// # Panics
+///
+/// Panicked if actions is empty.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let (index, key) = task.will(Update, wait::any_output().with(vec![
+///         wait::input::just_pressed().with(KeyCode::KeyA).map(|_| KeyCode::KeyA),
+///         wait::input::just_pressed().with(KeyCode::KeyB).map(|_| KeyCode::KeyB),
+///     ])).await;
+/// });
+/// ```
+pub fn any_output<I, O>() -> ActionSeed<Vec<Action<I, O>>, (usize, O)>
+where
+    I: 'static,
+    O: 'static,
+{
+    ActionSeed::new(|actions: Vec<Action<I, O>>, output| {
+        if actions.is_empty() {
+            panic!("The length of actions passed to `wait::any_output` must be greater than 0.")
+        }
+        let mut runner = RaceRunner::new(output);
+        for (i, action) in actions.into_iter().enumerate() {
+            runner.push(action, move |o| (i, o));
+        }
+        runner
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::action::{delay, once};
     use crate::actions;
-    use crate::prelude::{wait, Reactor};
+    use crate::prelude::{wait, Map, OmitInput, Reactor};
+    use crate::test_util::test;
     use crate::tests::test_app;
+    use alloc::vec;
     use bevy::app::{AppExit, Startup};
     use bevy::prelude::*;
     use bevy_test_helper::event::DirectEvents;
+    use bevy_test_helper::resource::count::Count;
     use bevy_test_helper::resource::DirectResourceControl;
 
     #[test]
@@ -135,4 +183,77 @@ mod tests {
         app.update();
         app.assert_message_comes(&mut er);
     }
+
+    #[test]
+    fn any_output_returns_index_and_output() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let (index, output) = task
+                    .will(
+                        Update,
+                        wait::any_output().with(vec![
+                            delay::frames().with(3).map(|_| "slow"),
+                            delay::frames().with(1).map(|_| "fast"),
+                        ]),
+                    )
+                    .await;
+                assert_eq!((index, output), (1, "fast"));
+                task.will(Update, once::message::app_exit_success()).await;
+            }));
+        });
+        let mut er = app.resource_mut::<Messages<AppExit>>().get_cursor();
+        app.update();
+        app.assert_message_not_comes(&mut er);
+        app.update();
+        app.assert_message_comes(&mut er);
+    }
+
+    #[test]
+    fn any_output_cancels_losers() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    wait::any_output().with(vec![
+                        test::count_on_cancel().into(),
+                        delay::frames().with(1).omit_input().into(),
+                        test::count_on_cancel().into(),
+                    ]),
+                )
+                .await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(2));
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn loser_handlers_are_called_on_reactor_cancel() {
+        let mut app = test_app();
+        app.update();
+        let reactor = app
+            .world_mut()
+            .spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    wait::any_output().with(vec![
+                        test::count_on_cancel().into(),
+                        wait::until(|| false).into(),
+                    ]),
+                )
+                .await;
+            }))
+            .id();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.world_mut().despawn(reactor);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
 }
@@ -0,0 +1,303 @@
+use crate::prelude::{ActionSeed, Output, Runner};
+use crate::runner::scoped::ScopedRunner;
+use crate::runner::{BoxedRunner, CancellationHandlers, RunnerIs};
+use bevy::prelude::*;
+
+/// Waits until at least `n` of the actions are completed.
+///
+/// The output value is the indices of the completed actions in the order they completed.
+/// The actions completed in the same frame are ordered by their indices,
+/// so the output can contain more than `n` indices.
+///
+/// The remaining actions are canceled: the cancellation handlers they registered are called.
+/// If you want to keep them running, use [`wait::first_n`](crate::prelude::wait::first_n) instead.
+///
+/// # Panics
+///
+/// Panicked if `n` is greater than the number of the actions.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::actions;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     // Proceed when 2 of the 3 players are ready.
+///     let ready: Vec<usize> = task.will(Update, wait::at_least(2).with(actions![
+///         wait::input::just_pressed().with(KeyCode::KeyA),
+///         wait::input::just_pressed().with(KeyCode::KeyB),
+///         wait::input::just_pressed().with(KeyCode::KeyC),
+///     ])).await;
+/// });
+/// ```
+#[inline]
+pub fn at_least<Actions>(n: usize) -> ActionSeed<Actions, Vec<usize>>
+where
+    Actions: IntoIterator<Item = ActionSeed> + 'static,
+{
+    quorum(n, false, "wait::at_least")
+}
+
+/// Waits until the first `n` of the actions are completed, and keeps the remaining actions running.
+///
+/// The output value is the same as [`wait::at_least`](crate::prelude::wait::at_least).
+///
+/// The remaining actions are detached from this action and keep running in the same schedule
+/// until they complete or the reactor is despawned, without blocking the reactor.
+///
+/// # Panics
+///
+/// Panicked if `n` is greater than the number of the actions.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::actions;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Message)]
+/// struct ObjectiveCleared(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     // Open the gate when 3 of the 5 objectives are cleared,
+///     // and keep counting the rest while the reactor is alive.
+///     task.will(Update, wait::first_n(3).with(
+///         (0..5).map(|i| wait::message::comes_and(move |cleared: &ObjectiveCleared| cleared.0 == i))
+///     )).await;
+/// });
+/// ```
+#[inline]
+pub fn first_n<Actions>(n: usize) -> ActionSeed<Actions, Vec<usize>>
+where
+    Actions: IntoIterator<Item = ActionSeed> + 'static,
+{
+    quorum(n, true, "wait::first_n")
+}
+
+fn quorum<Actions>(n: usize, detach: bool, name: &'static str) -> ActionSeed<Actions, Vec<usize>>
+where
+    Actions: IntoIterator<Item = ActionSeed> + 'static,
+{
+    ActionSeed::new(move |actions: Actions, output| {
+        let runners = actions
+            .into_iter()
+            .map(|action| {
+                Some(ScopedRunner::new(
+                    action.with(()).create_runner(Output::default()),
+                ))
+            })
+            .collect::<Vec<_>>();
+        if runners.len() < n {
+            panic!(
+                "`n` passed to `{name}` must not be greater than the number of the actions ({}).",
+                runners.len()
+            );
+        }
+        QuorumRunner {
+            n,
+            detach,
+            runners,
+            completed: Vec::new(),
+            output,
+        }
+    })
+}
+
+struct QuorumRunner {
+    n: usize,
+    detach: bool,
+    runners: Vec<Option<ScopedRunner>>,
+    completed: Vec<usize>,
+    output: Output<Vec<usize>>,
+}
+
+impl QuorumRunner {
+    fn release_remaining(&mut self, world: &mut World, token: &mut CancellationHandlers) {
+        let waker = token.waker();
+        for runner in core::mem::take(&mut self.runners).into_iter().flatten() {
+            match &waker {
+                Some(waker) if self.detach => waker.detach(world, BoxedRunner::new(runner)),
+                _ => runner.cancel(world, token),
+            }
+        }
+    }
+}
+
+impl Runner for QuorumRunner {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let mut all_sleeping = true;
+        if self.completed.len() < self.n {
+            for (i, slot) in self.runners.iter_mut().enumerate() {
+                let Some(runner) = slot else {
+                    continue;
+                };
+                match runner.run(world, token) {
+                    RunnerIs::Canceled => return RunnerIs::Canceled,
+                    RunnerIs::Completed => {
+                        slot.take();
+                        self.completed.push(i);
+                    }
+                    runner_is => all_sleeping &= runner_is.is_sleeping(),
+                }
+            }
+        }
+        if self.n <= self.completed.len() {
+            self.release_remaining(world, token);
+            self.output.set(core::mem::take(&mut self.completed));
+            RunnerIs::Completed
+        } else {
+            RunnerIs::pending(all_sleeping)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once, wait};
+    use crate::actions;
+    use crate::prelude::{
+        ActionSeed, CancellationHandlers, Reactor, Runner, RunnerIs, RunnerWaker, Then,
+    };
+    use crate::test_util::test;
+    use crate::tests::{increment_count, test_app};
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Resource, World};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Resource, Debug, Default, Eq, PartialEq)]
+    struct Completed(Vec<usize>);
+
+    #[test]
+    fn at_least_cancels_remaining() {
+        let mut app = test_app();
+        app.init_resource::<Completed>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let completed = task
+                    .will(
+                        Update,
+                        wait::at_least(2).with(actions![
+                            delay::frames().with(2).then(increment_count()),
+                            delay::frames().with(1),
+                            test::count_on_cancel(),
+                            delay::frames().with(1),
+                        ]),
+                    )
+                    .await;
+                task.will(Update, once::res::insert().with(Completed(completed)))
+                    .await;
+                task.will(Update, wait::until(|| false)).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Completed(vec![]));
+        app.update();
+        app.assert_resource_eq(Completed(vec![1, 3]));
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn first_n_keeps_remaining_running() {
+        let mut app = test_app();
+        app.init_resource::<Completed>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let completed = task
+                    .will(
+                        Update,
+                        wait::first_n(2).with(actions![
+                            delay::frames().with(2).then(increment_count()),
+                            delay::frames().with(1),
+                            test::count_on_cancel(),
+                            delay::frames().with(1),
+                        ]),
+                    )
+                    .await;
+                task.will(Update, once::res::insert().with(Completed(completed)))
+                    .await;
+                task.will(Update, wait::until(|| false)).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Completed(vec![1, 3]));
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[derive(Resource)]
+    struct DetachedWaker(RunnerWaker);
+
+    struct CountAndSleepRunner;
+
+    impl Runner for CountAndSleepRunner {
+        fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+            world.resource_mut::<Count>().increment();
+            world.insert_resource(DetachedWaker(token.waker().unwrap()));
+            RunnerIs::Sleeping
+        }
+    }
+
+    #[test]
+    fn sleeping_detached_runner_runs_only_when_woken() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    wait::first_n(1).with(actions![
+                        once::run(|| {}),
+                        ActionSeed::new(|_, _| CountAndSleepRunner),
+                    ]),
+                )
+                .await;
+                task.will(Update, wait::until(|| false)).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(2));
+        for _ in 0..3 {
+            app.update();
+            app.assert_resource_eq(Count(2));
+        }
+        app.world().resource::<DetachedWaker>().0.wake();
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn detached_runners_are_canceled_with_reactor() {
+        let mut app = test_app();
+        app.update();
+        let reactor = app
+            .world_mut()
+            .spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    wait::first_n(1).with(actions![once::run(|| {}), test::count_on_cancel()]),
+                )
+                .await;
+                task.will(Update, wait::until(|| false)).await;
+            }))
+            .id();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.world_mut().despawn(reactor);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -61,14 +61,22 @@ where
     O2: 'static,
 {
     fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let mut all_sleeping = true;
         if self.o1.is_none() {
-            if let RunnerIs::Canceled = self.r1.run(world, token) {
-                return RunnerIs::Canceled;
+            match self.r1.run(world, token) {
+                RunnerIs::Canceled => return RunnerIs::Canceled,
+                runner_is => all_sleeping &= !matches!(runner_is, RunnerIs::Running),
             }
         }
-        if self.o2.is_none() && self.r2.run(world, token).is_cancel() {
-            return RunnerIs::Canceled;
+        if self.o2.is_none() {
+            match self.r2.run(world, token) {
+                RunnerIs::Canceled => return RunnerIs::Canceled,
+                runner_is => all_sleeping &= !matches!(runner_is, RunnerIs::Running),
+            }
+        }
+        match output_combine!(&self.o1, &self.o2, self.output) {
+            RunnerIs::Completed => RunnerIs::Completed,
+            _ => RunnerIs::pending(all_sleeping),
         }
-        output_combine!(&self.o1, &self.o2, self.output)
     }
 }
@@ -94,9 +94,10 @@ where
         world: &mut World,
         token: &mut CancellationHandlers,
     ) -> crate::prelude::RunnerIs {
-        match self.r1.run(world, token) {
+        let r1_is = self.r1.run(world, token);
+        match r1_is {
             RunnerIs::Canceled => return RunnerIs::Canceled,
-            RunnerIs::Running => {}
+            RunnerIs::Running | RunnerIs::Sleeping => {}
             RunnerIs::Completed => {
                 let lhs = self.o1.take().expect("An output value hasn't been set!!!");
                 self.output.set(Either::Left(lhs));
@@ -107,6 +108,7 @@ where
         match self.r2.run(world, token) {
             RunnerIs::Canceled => RunnerIs::Canceled,
             RunnerIs::Running => RunnerIs::Running,
+            RunnerIs::Sleeping => RunnerIs::pending(r1_is.is_sleeping()),
             RunnerIs::Completed => {
                 let rhs = self.o2.take().expect("An output value hasn't been set!!!");
                 self.output.set(Either::Right(rhs));
@@ -1,9 +1,18 @@
 //! [`wait::message`] creates a task related to waiting to receive messages.
+//!
+//! While no messages are received, the actions sleep and are woken when the message is written,
+//! so idle reactors waiting for rare messages are not run every frame.
 
 use crate::prelude::seed::ActionSeed;
-use crate::prelude::wait;
+use crate::prelude::{wait, CancellationHandlers, Runner, RunnerIs};
+use crate::runner::BoxedRunner;
 use bevy::ecs::message::MessageCursor;
 use bevy::prelude::*;
+use core::marker::PhantomData;
+use core::time::Duration;
+pub use subscription::{next, MessageSubscription};
+
+mod subscription;
 
 /// Waits until the message is received.
 ///
@@ -22,7 +31,7 @@ pub fn comes<M>() -> ActionSeed
 where
     M: Message,
 {
-    wait::until(
+    sleep_until_written::<M, _, _>(wait::until(
         |mut er: Local<Option<MessageCursor<M>>>, mut messages: ResMut<Messages<M>>| {
             if er.is_none() {
                 if 0 < messages.iter_current_update_messages().count() {
@@ -39,7 +48,7 @@ where
                 false
             }
         },
-    )
+    ))
 }
 
 /// Waits until the message is received and the message matches the predicate.
@@ -61,7 +70,7 @@ pub fn comes_and<M>(predicate: impl Fn(&M) -> bool + Send + Sync + 'static) -> A
 where
     M: Message,
 {
-    wait::until(
+    sleep_until_written::<M, _, _>(wait::until(
         move |mut er: Local<Option<MessageCursor<M>>>, mut messages: ResMut<Messages<M>>| {
             if er.is_none() {
                 let received = messages.iter_current_update_messages().any(&predicate);
@@ -79,7 +88,7 @@ where
                 false
             }
         },
-    )
+    ))
 }
 
 /// Waits until the message is received.
@@ -127,7 +136,7 @@ pub fn read_and<M>(predicate: impl Fn(&M) -> bool + Send + Sync + 'static) -> Ac
 where
     M: Message + Clone,
 {
-    wait::output(
+    sleep_until_written::<M, _, _>(wait::output(
         move |mut er: Local<Option<MessageCursor<M>>>, mut messages: ResMut<Messages<M>>| {
             if er.is_none() {
                 let message = {
@@ -149,18 +158,190 @@ where
                 None
             }
         },
+    ))
+}
+
+/// Waits until at least one message is received, and returns all the messages received in that frame.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::window::FileDragAndDrop;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let dropped: Vec<FileDragAndDrop> = task.will(Update, wait::message::read_all::<FileDragAndDrop>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn read_all<M>() -> ActionSeed<(), Vec<M>>
+where
+    M: Message + Clone,
+{
+    sleep_until_written::<M, _, _>(wait::output(
+        |mut er: Local<Option<MessageCursor<M>>>, mut messages: ResMut<Messages<M>>| {
+            let received = read_cloned(&mut er, &messages);
+            if received.is_empty() {
+                None
+            } else {
+                messages.clear();
+                Some(received)
+            }
+        },
+    ))
+}
+
+/// Waits until `n` messages are received across frames, and returns them in the order they were received.
+///
+/// The messages are cleared when the action completes,
+/// so if more messages than needed are received in the last frame, the rest are discarded.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Message, Clone)]
+/// struct Coin;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::message::read_n::<Coin>(3)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn read_n<M>(n: usize) -> ActionSeed<(), Vec<M>>
+where
+    M: Message + Clone,
+{
+    sleep_until_written::<M, _, _>(wait::output(
+        move |mut er: Local<Option<MessageCursor<M>>>,
+              mut collected: Local<Vec<M>>,
+              mut messages: ResMut<Messages<M>>| {
+            let remaining = n - collected.len();
+            collected.extend(read_cloned(&mut er, &messages).into_iter().take(remaining));
+            if collected.len() < n {
+                return None;
+            }
+            messages.clear();
+            Some(core::mem::take(&mut *collected))
+        },
+    ))
+}
+
+/// Collects the messages received during `duration`, and returns them in the order they were received.
+///
+/// The duration is measured by [`Time`] in the same way as [`delay::time`](crate::prelude::delay::time),
+/// and the output is empty if no messages are received.
+/// The messages are cleared when the action completes.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Message, Clone)]
+/// struct Vote(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     let votes: Vec<Vote> = task.will(Update, wait::message::collect_for::<Vote>(Duration::from_secs(10))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn collect_for<M>(duration: Duration) -> ActionSeed<(), Vec<M>>
+where
+    M: Message + Clone,
+{
+    wait::output(
+        move |mut er: Local<Option<MessageCursor<M>>>,
+              mut collected: Local<Vec<M>>,
+              mut timer: Local<Option<Timer>>,
+              mut messages: ResMut<Messages<M>>,
+              time: Res<Time>| {
+            collected.extend(read_cloned(&mut er, &messages));
+            let finished = timer
+                .get_or_insert_with(|| Timer::new(duration, TimerMode::Once))
+                .tick(time.delta())
+                .just_finished();
+            if !finished {
+                return None;
+            }
+            messages.clear();
+            Some(core::mem::take(&mut *collected))
+        },
     )
 }
 
+/// Returns the messages received since the previous call.
+///
+/// The first call returns the messages written in the current update,
+/// in the same way as [`wait::message::read`].
+fn read_cloned<M>(er: &mut Option<MessageCursor<M>>, messages: &Messages<M>) -> Vec<M>
+where
+    M: Message + Clone,
+{
+    if let Some(er) = er.as_mut() {
+        return er.read(messages).cloned().collect();
+    }
+    er.replace(messages.get_cursor_current());
+    messages.iter_current_update_messages().cloned().collect()
+}
+
+/// Makes the runner created from `seed` sleep until the message `M` is written.
+fn sleep_until_written<M, I, O>(seed: ActionSeed<I, O>) -> ActionSeed<I, O>
+where
+    M: Message,
+    I: 'static,
+    O: 'static,
+{
+    ActionSeed::new(|input, output| SleepUntilWrittenRunner::<M> {
+        runner: seed.create_runner(input, output),
+        _m: PhantomData,
+    })
+}
+
+struct SleepUntilWrittenRunner<M> {
+    runner: BoxedRunner,
+    _m: PhantomData<fn() -> M>,
+}
+
+impl<M: Message> Runner for SleepUntilWrittenRunner<M> {
+    fn run(
+        &mut self,
+        world: &mut World,
+        cancellation_handlers: &mut CancellationHandlers,
+    ) -> RunnerIs {
+        match self.runner.run(world, cancellation_handlers) {
+            RunnerIs::Running => {
+                let Some(waker) = cancellation_handlers.waker() else {
+                    return RunnerIs::Running;
+                };
+                waker.wake_on_message::<M>(world);
+                RunnerIs::Sleeping
+            }
+            other => other,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::action::{once, wait};
-    use crate::prelude::{Either, Pipe, Reactor, Then};
+    use crate::prelude::{
+        ActionSeed, CancellationHandlers, Either, Pipe, Reactor, Runner, RunnerIs, Then,
+    };
+    use crate::runner::BoxedRunner;
     use crate::tests::test_app;
     use bevy::app::{App, Startup, Update};
     use bevy::prelude::*;
+    use bevy::time::TimeUpdateStrategy;
     use bevy_test_helper::event::*;
+    use bevy_test_helper::resource::count::Count;
     use bevy_test_helper::resource::DirectResourceControl;
+    use core::time::Duration;
 
     #[test]
     fn wait_until_message_consumed_messages() {
@@ -279,4 +460,145 @@ mod tests {
         app.update();
         app.assert_resource_eq(PredicateMessage(true));
     }
+
+    #[derive(Message, Clone, Debug, Eq, PartialEq)]
+    pub(super) struct NumMessage(pub(super) usize);
+
+    #[derive(Resource, Debug, Eq, PartialEq)]
+    pub(super) struct Received(pub(super) Vec<NumMessage>);
+
+    #[test]
+    fn read_all_messages_in_frame() {
+        let mut app = test_app();
+        app.add_message::<NumMessage>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let received = task
+                    .will(Update, wait::message::read_all::<NumMessage>())
+                    .await;
+                task.will(Update, once::res::insert().with(Received(received)))
+                    .await;
+            }));
+        });
+        app.update();
+        app.update();
+        assert!(!app.world().contains_resource::<Received>());
+
+        app.write(NumMessage(1));
+        app.write(NumMessage(2));
+        app.update();
+        app.assert_resource_eq(Received(vec![NumMessage(1), NumMessage(2)]));
+    }
+
+    #[test]
+    fn read_n_messages_across_frames() {
+        let mut app = test_app();
+        app.add_message::<NumMessage>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let received = task
+                    .will(Update, wait::message::read_n::<NumMessage>(3))
+                    .await;
+                task.will(Update, once::res::insert().with(Received(received)))
+                    .await;
+            }));
+        });
+        app.update();
+        app.write(NumMessage(1));
+        app.update();
+        app.write(NumMessage(2));
+        app.update();
+        assert!(!app.world().contains_resource::<Received>());
+
+        app.write(NumMessage(3));
+        app.write(NumMessage(4));
+        app.update();
+        app.assert_resource_eq(Received(vec![NumMessage(1), NumMessage(2), NumMessage(3)]));
+    }
+
+    #[test]
+    fn read_n_does_not_clear_messages_before_completion() {
+        let mut app = test_app();
+        app.add_message::<NumMessage>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::message::read_n::<NumMessage>(2))
+                    .await;
+            }));
+        });
+        app.update();
+        app.write(NumMessage(1));
+        app.update();
+        assert_eq!(app.world().resource::<Messages<NumMessage>>().len(), 1);
+    }
+
+    #[test]
+    fn collect_messages_for_duration() {
+        let mut app = test_app();
+        app.add_message::<NumMessage>();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let received = task
+                    .will(
+                        Update,
+                        wait::message::collect_for::<NumMessage>(Duration::from_millis(250)),
+                    )
+                    .await;
+                task.will(Update, once::res::insert().with(Received(received)))
+                    .await;
+            }));
+        });
+        app.update();
+        app.write(NumMessage(1));
+        app.update();
+        app.write(NumMessage(2));
+        app.update();
+        assert!(!app.world().contains_resource::<Received>());
+
+        app.update();
+        app.assert_resource_eq(Received(vec![NumMessage(1), NumMessage(2)]));
+    }
+
+    struct CountRuns(BoxedRunner);
+
+    impl Runner for CountRuns {
+        fn run(
+            &mut self,
+            world: &mut World,
+            cancellation_handlers: &mut CancellationHandlers,
+        ) -> RunnerIs {
+            world.resource_mut::<Count>().increment();
+            self.0.run(world, cancellation_handlers)
+        }
+    }
+
+    #[test]
+    fn sleep_until_message_written() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    ActionSeed::new(|input, output| {
+                        CountRuns(wait::message::comes::<TestEvent1>().create_runner(input, output))
+                    }),
+                )
+                .await;
+                task.will(Update, once::res::insert().with(Count(100)))
+                    .await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        for _ in 0..3 {
+            app.update();
+            app.assert_resource_eq(Count(1));
+        }
+        app.write(TestEvent1);
+        app.update();
+        app.assert_resource_eq(Count(100));
+    }
 }
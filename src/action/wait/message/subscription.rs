@@ -0,0 +1,228 @@
+use crate::prelude::{ActionSeed, CancellationHandlers, Output, Runner, RunnerIs, RunnerWaker};
+use crate::runner::waker::WakeInterests;
+use alloc::collections::VecDeque;
+use alloc::sync::{Arc, Weak};
+use alloc::vec::Vec;
+use bevy::ecs::message::MessageCursor;
+use bevy::platform::sync::Mutex;
+use bevy::prelude::{Message, Messages, Resource, World};
+
+/// A subscription to the message `M` created by [`ReactorTask::subscribe`].
+///
+/// It buffers the messages written after it was created until they are read with [`wait::message::next`],
+/// so no messages are missed or read twice between actions.
+///
+/// The messages are buffered until all clones of the subscription are dropped.
+/// Note that the messages consumed by [`wait::message::read`] and the other actions that clear the messages
+/// in the same run of the runners can't be buffered.
+///
+/// [`ReactorTask::subscribe`]: crate::prelude::ReactorTask::subscribe
+/// [`wait::message::next`]: crate::prelude::wait::message::next
+/// [`wait::message::read`]: crate::prelude::wait::message::read
+pub struct MessageSubscription<M: Message>(Arc<Mutex<SubscriptionState<M>>>);
+
+impl<M> MessageSubscription<M>
+where
+    M: Message + Clone,
+{
+    pub(crate) fn new(world: &mut World) -> Self {
+        if !world.contains_resource::<MessageSubscriptions<M>>() {
+            world.insert_resource(MessageSubscriptions::<M>(Mutex::new(Vec::new())));
+            WakeInterests::register(world, collect_subscriptions::<M>);
+        }
+        let cursor = world
+            .get_resource::<Messages<M>>()
+            .map(Messages::get_cursor_current)
+            .unwrap_or_default();
+        let state = Arc::new(Mutex::new(SubscriptionState {
+            cursor,
+            buffer: VecDeque::new(),
+            waker: None,
+        }));
+        if let Ok(mut subscriptions) = world.resource::<MessageSubscriptions<M>>().0.lock() {
+            subscriptions.push(Arc::downgrade(&state));
+        }
+        Self(state)
+    }
+}
+
+impl<M: Message> Clone for MessageSubscription<M> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+struct SubscriptionState<M: Message> {
+    cursor: MessageCursor<M>,
+    buffer: VecDeque<M>,
+    /// The waker of the [`wait::message::next`](crate::prelude::wait::message::next) sleeping until a message is buffered.
+    waker: Option<RunnerWaker>,
+}
+
+impl<M> SubscriptionState<M>
+where
+    M: Message + Clone,
+{
+    fn collect(&mut self, messages: &Messages<M>) {
+        self.buffer.extend(self.cursor.read(messages).cloned());
+        if self.buffer.is_empty() {
+            return;
+        }
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+#[derive(Resource)]
+struct MessageSubscriptions<M: Message>(Mutex<Vec<Weak<Mutex<SubscriptionState<M>>>>>);
+
+fn collect_subscriptions<M: Message + Clone>(world: &World) {
+    let Some(subscriptions) = world.get_resource::<MessageSubscriptions<M>>() else {
+        return;
+    };
+    let Ok(mut subscriptions) = subscriptions.0.lock() else {
+        return;
+    };
+    let messages = world.get_resource::<Messages<M>>();
+    subscriptions.retain(|subscription| {
+        let Some(state) = subscription.upgrade() else {
+            return false;
+        };
+        if let (Some(messages), Ok(mut state)) = (messages, state.lock()) {
+            state.collect(messages);
+        }
+        true
+    });
+}
+
+/// Waits until a message is buffered in the [`MessageSubscription`], and returns the oldest one.
+///
+/// Unlike [`wait::message::read`](crate::prelude::wait::message::read),
+/// the messages written while the reactor is running other actions are returned in order by the following calls.
+///
+/// While no messages are buffered, the action sleeps and is woken when the message is written.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Message, Clone)]
+/// struct Damaged(u32);
+///
+/// Reactor::schedule(|task| async move{
+///     let subscription = task.subscribe::<Damaged>();
+///     loop {
+///         let Damaged(damage) = task.will(Update, wait::message::next(&subscription)).await;
+///         // The messages written while playing the animation are not missed.
+///         task.will(Update, delay::frames().with(30)).await;
+///     }
+/// });
+/// ```
+pub fn next<M>(subscription: &MessageSubscription<M>) -> ActionSeed<(), M>
+where
+    M: Message + Clone,
+{
+    let subscription = subscription.clone();
+    ActionSeed::new(move |_, output| NextRunner {
+        subscription,
+        output,
+    })
+}
+
+struct NextRunner<M: Message> {
+    subscription: MessageSubscription<M>,
+    output: Output<M>,
+}
+
+impl<M> Runner for NextRunner<M>
+where
+    M: Message + Clone,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let Ok(mut state) = self.subscription.0.lock() else {
+            return RunnerIs::Running;
+        };
+        if let Some(messages) = world.get_resource::<Messages<M>>() {
+            state.collect(messages);
+        }
+        if let Some(message) = state.buffer.pop_front() {
+            self.output.set(message);
+            return RunnerIs::Completed;
+        }
+        match token.waker() {
+            Some(waker) => {
+                state.waker.replace(waker);
+                RunnerIs::Sleeping
+            }
+            None => RunnerIs::Running,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait::message::tests::{NumMessage, Received};
+    use crate::action::{delay, once, wait};
+    use crate::prelude::Reactor;
+    use crate::tests::test_app;
+    use alloc::vec;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::Commands;
+    use bevy_test_helper::event::DirectEvents;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn buffer_messages_written_between_actions() {
+        let mut app = test_app();
+        app.add_message::<NumMessage>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let subscription = task.subscribe::<NumMessage>();
+                task.will(Update, delay::frames().with(5)).await;
+                let first = task.will(Update, wait::message::next(&subscription)).await;
+                let second = task.will(Update, wait::message::next(&subscription)).await;
+                task.will(
+                    Update,
+                    once::res::insert().with(Received(vec![first, second])),
+                )
+                .await;
+            }));
+        });
+        app.update();
+        app.write(NumMessage(1));
+        app.update();
+        app.write(NumMessage(2));
+        app.update();
+        assert!(!app.world().contains_resource::<Received>());
+        for _ in 0..10 {
+            app.update();
+        }
+        app.assert_resource_eq(Received(vec![NumMessage(1), NumMessage(2)]));
+    }
+
+    #[test]
+    fn next_waits_for_message_written_after_subscribed() {
+        let mut app = test_app();
+        app.add_message::<NumMessage>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let subscription = task.subscribe::<NumMessage>();
+                let message = task.will(Update, wait::message::next(&subscription)).await;
+                task.will(Update, once::res::insert().with(Received(vec![message])))
+                    .await;
+            }));
+        });
+        app.write(NumMessage(0));
+        app.update();
+        app.update();
+        assert!(!app.world().contains_resource::<Received>());
+
+        app.write(NumMessage(1));
+        app.update();
+        app.assert_resource_eq(Received(vec![NumMessage(1)]));
+    }
+}
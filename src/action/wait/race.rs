@@ -0,0 +1,407 @@
+//! [`wait::race`] creates actions that wait until one of the actions is completed, and cancel the others.
+//!
+//! [`wait::either`](crate::prelude::wait::either) and [`wait::any`](crate::prelude::wait::any)
+//! just drop the actions that didn't complete, so the side effects started by them are left as they are.
+//! The actions in this module call the cancellation handlers registered by the losers
+//! via [`CancellationHandlers`](crate::prelude::CancellationHandlers) when the winner completes,
+//! in the same way as when the reactor is canceled.
+//!
+//! [`wait::any_output`](crate::prelude::wait::any_output) and [`wait_select!`](crate::wait_select) cancel the losers as well.
+
+use crate::action::Action;
+use crate::prelude::{ActionSeed, Either, Output, Runner, RunnerIs};
+use crate::runner::scoped::ScopedRunner;
+use crate::runner::CancellationHandlers;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use bevy::prelude::World;
+
+/// Waits until either of the two actions is completed, and cancels the other.
+///
+/// This is the same as [`wait::either`](crate::prelude::wait::either),
+/// except that the cancellation handlers registered by the loser are called.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let either = task.will(Update, wait::race::either(
+///         wait::input::just_pressed().with(KeyCode::KeyA),
+///         wait::input::just_pressed().with(KeyCode::KeyB),
+///     )).await;
+///     match either {
+///         Either::Left(_) => info!("A was pressed first"),
+///         Either::Right(_) => info!("B was pressed first"),
+///     }
+/// });
+/// ```
+pub fn either<LI, LO, RI, RO>(
+    lhs: impl Into<Action<LI, LO>> + 'static,
+    rhs: impl Into<Action<RI, RO>> + 'static,
+) -> Action<(LI, RI), Either<LO, RO>>
+where
+    LI: 'static,
+    LO: 'static,
+    RI: 'static,
+    RO: 'static,
+{
+    let Action(li, ls) = lhs.into();
+    let Action(ri, rs) = rhs.into();
+    ActionSeed::new(move |(li, ri): (LI, RI), output| {
+        let mut runner = RaceRunner::new(output);
+        runner.push(ls.with(li), Either::Left);
+        runner.push(rs.with(ri), Either::Right);
+        runner
+    })
+    .with((li, ri))
+}
+
+/// Waits until one of the actions is completed, and cancels the others.
+///
+/// The output value is the index of the completed action.
+/// This is the same as [`wait::any`](crate::prelude::wait::any),
+/// except that the cancellation handlers registered by the losers are called.
+///
+/// # Panics
+///
+/// Panicked if actions is empty.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::actions;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let index: usize = task.will(Update, wait::race::any().with(actions![
+///         wait::input::just_pressed().with(KeyCode::KeyB),
+///         wait::message::comes::<AppExit>()
+///     ])).await;
+/// });
+/// ```
+pub fn any<Actions>() -> ActionSeed<Actions, usize>
+where
+    Actions: IntoIterator<Item = ActionSeed> + 'static,
+{
+    ActionSeed::new(|actions: Actions, output| {
+        let mut runner = RaceRunner::new(output);
+        for (i, action) in actions.into_iter().enumerate() {
+            runner.push(action.with(()), move |_| i);
+        }
+        if runner.branches.is_empty() {
+            panic!("The length of actions passed to `wait::race::any` must be greater than 0.")
+        }
+        runner
+    })
+}
+
+macro_rules! select_enum {
+    ($name: ident, $len: literal; $($variant: ident),+) => {
+        #[doc = concat!("The output of [`wait_select!`](crate::wait_select) with ", $len, " actions.")]
+        ///
+        /// Each variant holds the output of the action at the same position.
+        #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+        pub enum $name<$($variant,)+> {
+            $(
+            #[doc = concat!("The action `", stringify!($variant), "` completed first.")]
+            $variant($variant),
+            )+
+        }
+    };
+}
+
+select_enum!(Select2, 2; A, B);
+select_enum!(Select3, 3; A, B, C);
+select_enum!(Select4, 4; A, B, C, D);
+select_enum!(Select5, 5; A, B, C, D, E);
+select_enum!(Select6, 6; A, B, C, D, E, F);
+select_enum!(Select7, 7; A, B, C, D, E, F, G);
+select_enum!(Select8, 8; A, B, C, D, E, F, G, H);
+
+/// Waits until one of the actions is completed, cancels the others,
+/// and outputs the output of the completed action as a variant of `SelectN`.
+///
+/// The actions can have different inputs and outputs, and up to 8 actions can be passed.
+/// The output type is [`Select2`] to [`Select8`] depending on the number of the actions,
+/// whose variants `A`, `B`, `C`, ... hold the outputs of the actions in the order they were passed.
+///
+/// Like [`wait::race::any`](crate::prelude::wait::race::any), the cancellation handlers registered by the losers are called.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use bevy_flurx::wait_select;
+///
+/// Reactor::schedule(|task| async move{
+///     let selected = task.will(Update, wait_select![
+///         wait::input::just_pressed().with(KeyCode::KeyA).map(|_| 'A'),
+///         wait::message::read::<AppExit>(),
+///         delay::time().with(Duration::from_secs(3)),
+///     ]).await;
+///     match selected {
+///         wait::race::Select3::A(key) => info!("{key} was pressed"),
+///         wait::race::Select3::B(exit) => info!("{exit:?}"),
+///         wait::race::Select3::C(()) => info!("timeout"),
+///     }
+/// });
+/// ```
+#[macro_export]
+macro_rules! wait_select {
+    ($a: expr, $b: expr $(,)?) => {
+        $crate::__wait_select!(Select2; A $a, B $b)
+    };
+    ($a: expr, $b: expr, $c: expr $(,)?) => {
+        $crate::__wait_select!(Select3; A $a, B $b, C $c)
+    };
+    ($a: expr, $b: expr, $c: expr, $d: expr $(,)?) => {
+        $crate::__wait_select!(Select4; A $a, B $b, C $c, D $d)
+    };
+    ($a: expr, $b: expr, $c: expr, $d: expr, $e: expr $(,)?) => {
+        $crate::__wait_select!(Select5; A $a, B $b, C $c, D $d, E $e)
+    };
+    ($a: expr, $b: expr, $c: expr, $d: expr, $e: expr, $f: expr $(,)?) => {
+        $crate::__wait_select!(Select6; A $a, B $b, C $c, D $d, E $e, F $f)
+    };
+    ($a: expr, $b: expr, $c: expr, $d: expr, $e: expr, $f: expr, $g: expr $(,)?) => {
+        $crate::__wait_select!(Select7; A $a, B $b, C $c, D $d, E $e, F $f, G $g)
+    };
+    ($a: expr, $b: expr, $c: expr, $d: expr, $e: expr, $f: expr, $g: expr, $h: expr $(,)?) => {
+        $crate::__wait_select!(Select8; A $a, B $b, C $c, D $d, E $e, F $f, G $g, H $h)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __wait_select {
+    ($select: ident; $($variant: ident $action: expr),+) => {
+        $crate::prelude::wait::race::private::select([
+            $($crate::prelude::wait::race::private::branch($action, $crate::prelude::wait::race::$select::$variant),)+
+        ])
+    };
+}
+
+#[doc(hidden)]
+pub mod private {
+    use crate::action::wait::race::RaceRunner;
+    use crate::action::Action;
+    use crate::prelude::ActionSeed;
+    use alloc::boxed::Box;
+
+    pub struct Branch<S>(Box<dyn FnOnce(&mut RaceRunner<S>) + Send + Sync>);
+
+    pub fn branch<I, O, S>(
+        action: impl Into<Action<I, O>> + 'static,
+        variant: fn(O) -> S,
+    ) -> Branch<S>
+    where
+        I: Send + Sync + 'static,
+        O: 'static,
+        S: 'static,
+    {
+        let action = action.into();
+        Branch(Box::new(move |runner| runner.push(action, variant)))
+    }
+
+    pub fn select<S: 'static, const N: usize>(
+        branches: [Branch<S>; N],
+    ) -> Action<[Branch<S>; N], S> {
+        ActionSeed::new(|branches: [Branch<S>; N], output| {
+            let mut runner = RaceRunner::new(output);
+            for branch in branches {
+                (branch.0)(&mut runner);
+            }
+            runner
+        })
+        .with(branches)
+    }
+}
+
+type TakeOutput<P> = Box<dyn Fn() -> Option<P>>;
+
+/// Runs the actions until one of them completes, and cancels the others.
+///
+/// The output of each action is converted into `P` by the function passed to [`RaceRunner::push`].
+pub(crate) struct RaceRunner<P> {
+    branches: Vec<(ScopedRunner, TakeOutput<P>)>,
+    output: Output<P>,
+}
+
+impl<P> RaceRunner<P> {
+    #[inline]
+    pub(crate) const fn new(output: Output<P>) -> Self {
+        Self {
+            branches: Vec::new(),
+            output,
+        }
+    }
+
+    pub(crate) fn push<I, O>(&mut self, action: Action<I, O>, f: impl Fn(O) -> P + 'static)
+    where
+        I: 'static,
+        O: 'static,
+    {
+        let o = Output::default();
+        let runner = ScopedRunner::new(action.create_runner(o.clone()));
+        self.branches
+            .push((runner, Box::new(move || o.take().map(&f))));
+    }
+}
+
+impl<P> Runner for RaceRunner<P> {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let mut finished = None;
+        let mut all_sleeping = true;
+        for (i, (runner, _)) in self.branches.iter_mut().enumerate() {
+            match runner.run(world, token) {
+                RunnerIs::Completed => {
+                    finished.replace(i);
+                    break;
+                }
+                RunnerIs::Canceled => return RunnerIs::Canceled,
+                RunnerIs::Running => all_sleeping = false,
+                RunnerIs::Sleeping => continue,
+            }
+        }
+        let Some(finished_index) = finished else {
+            return RunnerIs::pending(all_sleeping);
+        };
+        let mut winner = None;
+        for (i, (runner, take_output)) in
+            core::mem::take(&mut self.branches).into_iter().enumerate()
+        {
+            if i == finished_index {
+                winner = take_output();
+            } else {
+                runner.cancel(world, token);
+            }
+        }
+        self.output
+            .set(winner.expect("The output value has not been set!!!"));
+        RunnerIs::Completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once, wait};
+    use crate::actions;
+    use crate::prelude::{Either, Map, Reactor};
+    use crate::test_util::test;
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, ResMut, Resource};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Resource, Debug, Default, Eq, PartialEq)]
+    struct Selected(Option<wait::race::Select3<usize, &'static str, ()>>);
+
+    #[test]
+    fn either_cancels_loser() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let either = task
+                    .will(
+                        Update,
+                        wait::race::either(test::count_on_cancel(), delay::frames().with(1)),
+                    )
+                    .await;
+                assert!(either.is_right());
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn any_cancels_losers() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let index = task
+                    .will(
+                        Update,
+                        wait::race::any().with(actions![
+                            test::count_on_cancel(),
+                            test::count_on_cancel(),
+                            once::run(|| {}),
+                        ]),
+                    )
+                    .await;
+                assert_eq!(index, 2);
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(2));
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn select_typed_branches() {
+        let mut app = test_app();
+        app.init_resource::<Selected>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let selected = task
+                    .will(
+                        Update,
+                        wait_select![
+                            delay::frames().with(3).map(|_| 3_usize),
+                            delay::frames().with(1).map(|_| "one"),
+                            test::count_on_cancel(),
+                        ],
+                    )
+                    .await;
+                task.will(
+                    Update,
+                    once::run(move |mut s: ResMut<Selected>| {
+                        s.0 = Some(selected);
+                    }),
+                )
+                .await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Selected(None));
+        app.update();
+        app.assert_resource_eq(Selected(Some(wait::race::Select3::B("one"))));
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn select_keeps_either_semantics() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let selected = task
+                    .will(Update, wait_select![once::run(|| 1), wait::until(|| false)])
+                    .await;
+                assert_eq!(selected, wait::race::Select2::A(1));
+                let either = task
+                    .will(Update, wait::race::either(once::run(|| 1), once::run(|| 2)))
+                    .await;
+                assert_eq!(either, Either::Left(1));
+                task.will(
+                    Update,
+                    once::run(|mut count: ResMut<Count>| {
+                        count.increment();
+                    }),
+                )
+                .await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
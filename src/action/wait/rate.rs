@@ -0,0 +1,493 @@
+use crate::action::Action;
+use crate::prelude::{ActionSeed, CancellationHandlers, Output, Runner, RunnerIs};
+use crate::runner::BoxedRunner;
+use alloc::boxed::Box;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::{Entity, Resource, Time, Timer, TimerMode, World};
+use core::any::TypeId;
+use core::time::Duration;
+
+/// Repeats the action created by `action`, and completes with the last output
+/// once the action hasn't completed for the duration passed as input.
+///
+/// The action is restarted every time it completes, and runs at most once per frame.
+/// The duration is measured by [`Time`] in the same way as [`delay::time`](crate::prelude::delay::time).
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy::window::WindowResized;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     // Wait until the window stops being resized for 300ms.
+///     let resized: WindowResized = task.will(Update, wait::debounce(|| wait::message::read::<WindowResized>())
+///         .with(Duration::from_millis(300))
+///     ).await;
+/// });
+/// ```
+#[inline]
+pub fn debounce<F, A, O>(action: F) -> ActionSeed<Duration, O>
+where
+    F: Fn() -> A + Send + Sync + 'static,
+    A: Into<Action<(), O>> + 'static,
+    O: 'static,
+{
+    ActionSeed::new(move |duration, output| DebounceRunner {
+        repeat: Repeat::new(action),
+        timer: Timer::new(duration, TimerMode::Once),
+        latest: None,
+        output,
+    })
+}
+
+/// Repeats the action created by `action`, and completes with its output at most once per the duration passed as input.
+///
+/// The first output completes this action immediately and starts the period.
+/// While the period lasts, the outputs are ignored, even if this action is awaited again,
+/// so awaiting it in a loop outputs at most once per period.
+/// The period is shared by the awaits of the same `throttle` call in the same reactor.
+///
+/// The action is restarted every time it completes, and runs at most once per frame.
+/// The duration is measured by [`Time`] in the same way as [`delay::time`](crate::prelude::delay::time).
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy::window::CursorMoved;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     loop {
+///         // Update the hover target at most 10 times per second.
+///         let moved: CursorMoved = task.will(Update, wait::throttle(|| wait::message::read::<CursorMoved>())
+///             .with(Duration::from_millis(100))
+///         ).await;
+///     }
+/// });
+/// ```
+#[inline]
+pub fn throttle<F, A, O>(action: F) -> ActionSeed<Duration, O>
+where
+    F: Fn() -> A + Send + Sync + 'static,
+    A: Into<Action<(), O>> + 'static,
+    O: 'static,
+{
+    ActionSeed::new(move |duration, output| ThrottleRunner {
+        repeat: Repeat::new(action),
+        key: TypeId::of::<F>(),
+        duration,
+        output,
+    })
+}
+
+/// Repeats the action created by `action`, and completes with the last output
+/// when the duration passed as input has elapsed since the action first completed.
+///
+/// Unlike [`wait::debounce`](crate::prelude::wait::debounce), the outputs arriving later don't restart the period;
+/// they replace the output to complete with.
+/// Unlike [`wait::throttle`](crate::prelude::wait::throttle), the first output is also delayed until the end of the period,
+/// so the output always trails the action.
+///
+/// The action is restarted every time it completes, and runs at most once per frame.
+/// The duration is measured by [`Time`] in the same way as [`delay::time`](crate::prelude::delay::time).
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy::window::CursorMoved;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     loop {
+///         // Update the hover target with the latest cursor position 100ms after it starts moving.
+///         let moved: CursorMoved = task.will(Update, wait::audit(|| wait::message::read::<CursorMoved>())
+///             .with(Duration::from_millis(100))
+///         ).await;
+///     }
+/// });
+/// ```
+#[inline]
+pub fn audit<F, A, O>(action: F) -> ActionSeed<Duration, O>
+where
+    F: Fn() -> A + Send + Sync + 'static,
+    A: Into<Action<(), O>> + 'static,
+    O: 'static,
+{
+    ActionSeed::new(move |duration, output| AuditRunner {
+        repeat: Repeat::new(action),
+        timer: Timer::new(duration, TimerMode::Once),
+        latest: None,
+        output,
+    })
+}
+
+/// Repeats the action created by `action`, and completes with the last output
+/// at the first interval of the number of frames passed as input after the action completed.
+///
+/// The intervals are counted in the same way as [`delay::frames`](crate::prelude::delay::frames):
+/// if the input is `2`, the output is checked on the 3rd, 5th, 7th, ... frames.
+///
+/// The action is restarted every time it completes, and runs at most once per frame.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::window::CursorMoved;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     // Sample the cursor position every 10 frames.
+///     let moved: CursorMoved = task.will(Update, wait::sample(|| wait::message::read::<CursorMoved>())
+///         .with(10)
+///     ).await;
+/// });
+/// ```
+#[inline]
+pub fn sample<F, A, O>(action: F) -> ActionSeed<usize, O>
+where
+    F: Fn() -> A + Send + Sync + 'static,
+    A: Into<Action<(), O>> + 'static,
+    O: 'static,
+{
+    ActionSeed::new(move |frames, output| SampleRunner {
+        repeat: Repeat::new(action),
+        frames,
+        frame_now: 0,
+        latest: None,
+        output,
+    })
+}
+
+/// The runner of the action that is restarted every time it completes.
+struct Repeat<O> {
+    create: Box<dyn Fn(Output<O>) -> BoxedRunner>,
+    runner: BoxedRunner,
+    output: Output<O>,
+}
+
+impl<O> Repeat<O>
+where
+    O: 'static,
+{
+    fn new<F, A>(action: F) -> Self
+    where
+        F: Fn() -> A + 'static,
+        A: Into<Action<(), O>>,
+    {
+        let create = Box::new(move |output: Output<O>| action().into().create_runner(output));
+        let output = Output::default();
+        Self {
+            runner: create(output.clone()),
+            create,
+            output,
+        }
+    }
+
+    /// Runs the action, and creates the next action if it completed.
+    ///
+    /// The next action is not run until the next call, so the action runs at most once per call.
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let runner_is = self.runner.run(world, token);
+        if runner_is.is_completed() {
+            self.runner = (self.create)(self.output.clone());
+        }
+        runner_is
+    }
+
+    fn take(&self) -> O {
+        self.output
+            .take()
+            .expect("The output value has not been set!!!")
+    }
+}
+
+struct DebounceRunner<O> {
+    repeat: Repeat<O>,
+    timer: Timer,
+    latest: Option<O>,
+    output: Output<O>,
+}
+
+impl<O> Runner for DebounceRunner<O>
+where
+    O: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let delta = match self.repeat.run(world, token) {
+            RunnerIs::Canceled => return RunnerIs::Canceled,
+            RunnerIs::Completed => {
+                self.latest.replace(self.repeat.take());
+                self.timer.reset();
+                Duration::ZERO
+            }
+            runner_is if self.latest.is_none() => return runner_is,
+            _ => world.resource::<Time>().delta(),
+        };
+        complete_if_finished(&mut self.timer, delta, &mut self.latest, &self.output)
+    }
+}
+
+/// The ends of the periods started by [`throttle`], keyed by the reactor and the `throttle` call.
+#[derive(Resource, Default)]
+struct ThrottlePeriods(HashMap<(Entity, TypeId), Duration>);
+
+struct ThrottleRunner<O> {
+    repeat: Repeat<O>,
+    key: TypeId,
+    duration: Duration,
+    output: Output<O>,
+}
+
+impl<O> Runner for ThrottleRunner<O>
+where
+    O: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let runner_is = self.repeat.run(world, token);
+        if !runner_is.is_completed() {
+            return runner_is;
+        }
+        let o = self.repeat.take();
+        let now = world.resource::<Time>().elapsed();
+        let reactor = token
+            .waker()
+            .map(|waker| waker.reactor())
+            .unwrap_or(Entity::PLACEHOLDER);
+        let mut periods = world.get_resource_or_init::<ThrottlePeriods>();
+        if periods
+            .0
+            .get(&(reactor, self.key))
+            .is_some_and(|end| now < *end)
+        {
+            return RunnerIs::Running;
+        }
+        // The ended periods are no different from the absent ones.
+        periods.0.retain(|_, end| now < *end);
+        periods.0.insert((reactor, self.key), now + self.duration);
+        self.output.set(o);
+        RunnerIs::Completed
+    }
+}
+
+struct AuditRunner<O> {
+    repeat: Repeat<O>,
+    timer: Timer,
+    latest: Option<O>,
+    output: Output<O>,
+}
+
+impl<O> Runner for AuditRunner<O>
+where
+    O: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let delta = match self.repeat.run(world, token) {
+            RunnerIs::Canceled => return RunnerIs::Canceled,
+            RunnerIs::Completed if self.latest.is_none() => {
+                self.latest.replace(self.repeat.take());
+                self.timer.reset();
+                Duration::ZERO
+            }
+            RunnerIs::Completed => {
+                self.latest.replace(self.repeat.take());
+                world.resource::<Time>().delta()
+            }
+            runner_is if self.latest.is_none() => return runner_is,
+            _ => world.resource::<Time>().delta(),
+        };
+        complete_if_finished(&mut self.timer, delta, &mut self.latest, &self.output)
+    }
+}
+
+fn complete_if_finished<O>(
+    timer: &mut Timer,
+    delta: Duration,
+    latest: &mut Option<O>,
+    output: &Output<O>,
+) -> RunnerIs {
+    if timer.tick(delta).just_finished() {
+        output.set(latest.take().expect("The output value has not been set!!!"));
+        RunnerIs::Completed
+    } else {
+        RunnerIs::Running
+    }
+}
+
+struct SampleRunner<O> {
+    repeat: Repeat<O>,
+    frames: usize,
+    frame_now: usize,
+    latest: Option<O>,
+    output: Output<O>,
+}
+
+impl<O> Runner for SampleRunner<O>
+where
+    O: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        match self.repeat.run(world, token) {
+            RunnerIs::Canceled => return RunnerIs::Canceled,
+            RunnerIs::Completed => {
+                self.latest.replace(self.repeat.take());
+            }
+            _ => {}
+        }
+        if self.frames <= self.frame_now {
+            self.frame_now = 0;
+            if let Some(o) = self.latest.take() {
+                self.output.set(o);
+                return RunnerIs::Completed;
+            }
+        }
+        self.frame_now += 1;
+        RunnerIs::Running
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Message, Resource};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test_helper::event::DirectEvents;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use core::time::Duration;
+
+    #[derive(Message, Resource, Clone, Debug, Eq, PartialEq)]
+    struct Value(usize);
+
+    #[test]
+    fn debounce_outputs_last_value_after_quiet_period() {
+        let mut app = test_app();
+        app.add_message::<Value>();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    wait::debounce(|| wait::message::read::<Value>())
+                        .with(Duration::from_millis(250))
+                        .pipe(once::res::insert()),
+                )
+                .await;
+            }));
+        });
+        app.update();
+        app.write(Value(1));
+        app.update();
+        app.write(Value(2));
+        app.update();
+        app.update();
+        app.update();
+        assert!(!app.world().contains_resource::<Value>());
+        app.update();
+        app.assert_resource_eq(Value(2));
+    }
+
+    #[test]
+    fn throttle_outputs_at_most_once_per_period() {
+        let mut app = test_app();
+        app.add_message::<Value>();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                loop {
+                    task.will(
+                        Update,
+                        wait::throttle(|| wait::message::read::<Value>())
+                            .with(Duration::from_millis(250))
+                            .pipe(once::res::insert()),
+                    )
+                    .await;
+                }
+            }));
+        });
+        app.update();
+        app.write(Value(1));
+        app.update();
+        app.assert_resource_eq(Value(1));
+        app.write(Value(2));
+        app.update();
+        app.write(Value(3));
+        app.update();
+        app.assert_resource_eq(Value(1));
+        app.update();
+        app.update();
+        app.write(Value(4));
+        app.update();
+        app.assert_resource_eq(Value(4));
+    }
+
+    #[test]
+    fn audit_outputs_last_value_at_end_of_period() {
+        let mut app = test_app();
+        app.add_message::<Value>();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    wait::audit(|| wait::message::read::<Value>())
+                        .with(Duration::from_millis(250))
+                        .pipe(once::res::insert()),
+                )
+                .await;
+            }));
+        });
+        app.update();
+        app.write(Value(1));
+        app.update();
+        app.write(Value(2));
+        app.update();
+        app.write(Value(3));
+        app.update();
+        assert!(!app.world().contains_resource::<Value>());
+        app.update();
+        app.assert_resource_eq(Value(3));
+    }
+
+    #[test]
+    fn sample_outputs_last_value_at_frame_interval() {
+        let mut app = test_app();
+        app.add_message::<Value>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    wait::sample(|| wait::message::read::<Value>())
+                        .with(2)
+                        .pipe(once::res::insert()),
+                )
+                .await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.update();
+        assert!(!app.world().contains_resource::<Value>());
+        app.write(Value(1));
+        app.update();
+        assert!(!app.world().contains_resource::<Value>());
+        app.write(Value(2));
+        app.update();
+        app.assert_resource_eq(Value(2));
+    }
+}
@@ -0,0 +1,304 @@
+//! [`wait::read_only`] creates tasks whose systems are evaluated outside the exclusive system of the schedule.
+//!
+//! The actions in [`wait`] run their systems in the exclusive system of the schedule every frame.
+//! The systems passed to these actions can only read the world,
+//! so they are evaluated in a system that takes a shared reference to the world instead,
+//! and hop to the exclusive system only when they finish.
+//! This only avoids the exclusive access to the world; it doesn't make the systems run in parallel
+//! with the systems that write to the world, since the shared reference to the whole world conflicts with them.
+//! What it saves is the exclusive system, which is skipped in the frames where no reactor has runners to run.
+//!
+//! If the system is not [`Send`] or has deferred buffers such as [`Commands`](bevy::prelude::Commands),
+//! it is evaluated in the exclusive system like [`wait::output`].
+//!
+//! actions
+//!
+//! - [`wait::read_only::output`]
+//! - [`wait::read_only::until`]
+
+use crate::action::seed::ActionSeed;
+use crate::prelude::{wait, CancellationHandlers, Output, Runner, RunnerIs, RunnerWaker};
+use crate::runner::parallel::ParallelRunner;
+use alloc::sync::Arc;
+use bevy::ecs::system::ReadOnlySystem;
+use bevy::platform::sync::Mutex;
+use bevy::prelude::{In, IntoSystem, System, SystemIn, SystemInput, World};
+
+/// Run until it returns [`Option::Some`], evaluating the system outside the exclusive system.
+/// The contents of Some will be return value of the task.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Hp(u32);
+///
+/// Reactor::schedule(|task| async move{
+///     let dead = task.will(Update, wait::read_only::output(|hps: Query<(Entity, &Hp)>|{
+///         hps.iter().find(|(_, hp)| hp.0 == 0).map(|(entity, _)| entity)
+///     })).await;
+/// });
+/// ```
+pub fn output<Sys, I, O, Marker>(system: Sys) -> ActionSeed<I::Inner<'static>, O>
+where
+    Sys: IntoSystem<I, Option<O>, Marker> + Send + Sync + 'static,
+    Sys::System: ReadOnlySystem,
+    I: SystemInput + 'static,
+    I::Inner<'static>: Clone + Send,
+    O: Send + 'static,
+{
+    ActionSeed::new(move |input, output| ReadOnlyRunner {
+        system: Some(IntoSystem::into_system(system)),
+        input,
+        output,
+        init: false,
+        slot: None,
+    })
+}
+
+/// Run until it returns true, evaluating the system outside the exclusive system.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Score(u32);
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::read_only::until(|score: Res<Score>|{
+///         100 <= score.0
+///     })).await;
+/// });
+/// ```
+#[inline]
+pub fn until<I, Sys, M>(system: Sys) -> ActionSeed<I::Inner<'static>>
+where
+    Sys: IntoSystem<I, bool, M> + Send + Sync + 'static,
+    Sys::System: ReadOnlySystem,
+    I: SystemInput + 'static,
+    I::Inner<'static>: Clone + Send,
+{
+    wait::read_only::output(system.pipe(|In(finish): In<bool>| finish.then_some(())))
+}
+
+type Slot<O> = Arc<Mutex<Option<O>>>;
+
+struct ReadOnlyRunner<Sys, O>
+where
+    Sys: System,
+{
+    system: Option<Sys>,
+    input: <Sys::In as SystemInput>::Inner<'static>,
+    output: Output<O>,
+    init: bool,
+    /// The slot the output is written to after the system is moved to the parallel runners.
+    slot: Option<Slot<O>>,
+}
+
+impl<Sys, O> Runner for ReadOnlyRunner<Sys, O>
+where
+    Sys: ReadOnlySystem<Out = Option<O>>,
+    SystemIn<'static, Sys>: Clone + Send + 'static,
+    O: Send + 'static,
+{
+    fn run(
+        &mut self,
+        world: &mut World,
+        cancellation_handlers: &mut CancellationHandlers,
+    ) -> RunnerIs {
+        if let Some(slot) = self.slot.as_ref() {
+            return match slot.lock().ok().and_then(|mut out| out.take()) {
+                Some(out) => {
+                    self.output.set(out);
+                    RunnerIs::Completed
+                }
+                None => RunnerIs::Sleeping,
+            };
+        }
+        let Some(system) = self.system.as_mut() else {
+            return RunnerIs::Completed;
+        };
+        if !self.init {
+            system.initialize(world);
+            self.init = true;
+        }
+
+        let out = system.run(self.input.clone(), world);
+        system.apply_deferred(world);
+        if let Ok(Some(o)) = out {
+            self.output.set(o);
+            return RunnerIs::Completed;
+        }
+        if system.has_deferred() || !system.is_send() {
+            return RunnerIs::Running;
+        }
+        let Some(waker) = cancellation_handlers.waker() else {
+            return RunnerIs::Running;
+        };
+        let slot = Slot::default();
+        let parallel_runners = waker.parallel_runners().clone();
+        parallel_runners.push(ParallelEvaluation {
+            system: self.system.take().unwrap(),
+            input: self.input.clone(),
+            slot: slot.clone(),
+            waker,
+        });
+        self.slot = Some(slot);
+        RunnerIs::Sleeping
+    }
+}
+
+struct ParallelEvaluation<Sys, O>
+where
+    Sys: System,
+{
+    system: Sys,
+    input: <Sys::In as SystemInput>::Inner<'static>,
+    slot: Slot<O>,
+    waker: RunnerWaker,
+}
+
+impl<Sys, O> ParallelRunner for ParallelEvaluation<Sys, O>
+where
+    Sys: ReadOnlySystem<Out = Option<O>>,
+    SystemIn<'static, Sys>: Clone + Send + 'static,
+    O: Send + 'static,
+{
+    fn evaluate(&mut self, world: &World) -> bool {
+        // The runner has been dropped, such as when the reactor is canceled.
+        if Arc::strong_count(&self.slot) == 1 {
+            return true;
+        }
+        let Ok(Some(out)) = self.system.run_readonly(self.input.clone(), world) else {
+            return false;
+        };
+        if let Ok(mut slot) = self.slot.lock() {
+            slot.replace(out);
+        }
+        self.waker.wake();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, Local, Res, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn until_resource_changes() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    wait::read_only::until(|count: Res<Count>| count.0 == 2)
+                        .then(once::res::insert().with(Count(100))),
+                )
+                .await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.world_mut().resource_mut::<Count>().0 = 2;
+        app.update();
+        app.assert_resource_eq(Count(100));
+    }
+
+    #[test]
+    fn output_with_input() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let num = task
+                    .will(
+                        Update,
+                        wait::read_only::output(|In(num): In<usize>, mut frames: Local<usize>| {
+                            *frames += 1;
+                            (*frames == 3).then_some(num + *frames)
+                        })
+                        .with(1),
+                    )
+                    .await;
+                task.will(Update, once::res::insert().with(Count(num)))
+                    .await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(4));
+    }
+
+    #[test]
+    fn evaluate_in_exclusive_system_if_system_has_deferred() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    wait::read_only::until(|mut commands: Commands, count: Res<Count>| {
+                        commands.queue(|world: &mut bevy::prelude::World| {
+                            world.resource_mut::<Count>().increment();
+                        });
+                        count.0 == 2
+                    }),
+                )
+                .await;
+                task.will(
+                    Update,
+                    once::run(|mut count: ResMut<Count>| {
+                        count.0 = 100;
+                    }),
+                )
+                .await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(2));
+        app.update();
+        app.assert_resource_eq(Count(100));
+    }
+
+    #[test]
+    fn stop_evaluating_after_reactor_despawned() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    wait::read_only::until(|count: Res<Count>| count.0 == 1)
+                        .then(once::res::insert().with(Count(100))),
+                )
+                .await;
+            }));
+        });
+        app.update();
+        let reactor = app
+            .world_mut()
+            .query_filtered::<bevy::prelude::Entity, bevy::prelude::With<crate::reactor::NativeReactor>>()
+            .single(app.world())
+            .unwrap();
+        app.world_mut().despawn(reactor);
+        app.world_mut().resource_mut::<Count>().0 = 1;
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
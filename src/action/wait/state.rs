@@ -1,11 +1,15 @@
 //! [`wait::state`] creates a task related to waiting to state update.
 
 use crate::action::wait;
-use crate::prelude::ActionSeed;
-use bevy::prelude::{In, Res, State, States};
+use crate::prelude::{ActionSeed, CancellationHandlers, Runner, RunnerIs};
+use crate::runner::BoxedRunner;
+use bevy::prelude::{In, Res, State, States, World};
+use core::marker::PhantomData;
 
 /// Waits until the state becomes the specified.
 ///
+/// While the state is different, the action sleeps and is woken when the state changes.
+///
 /// ## Examples
 ///
 /// ```no_run
@@ -28,7 +32,37 @@ pub fn becomes<S>() -> ActionSeed<S>
 where
     S: States + 'static,
 {
-    wait::until(move |In(expect): In<S>, state_now: Res<State<S>>| state_now.get() == &expect)
+    ActionSeed::new(|expect, output| SleepUntilStateChangedRunner::<S> {
+        runner: wait::until(move |In(expect): In<S>, state_now: Res<State<S>>| {
+            state_now.get() == &expect
+        })
+        .create_runner(expect, output),
+        _s: PhantomData,
+    })
+}
+
+struct SleepUntilStateChangedRunner<S> {
+    runner: BoxedRunner,
+    _s: PhantomData<fn() -> S>,
+}
+
+impl<S: States> Runner for SleepUntilStateChangedRunner<S> {
+    fn run(
+        &mut self,
+        world: &mut World,
+        cancellation_handlers: &mut CancellationHandlers,
+    ) -> RunnerIs {
+        match self.runner.run(world, cancellation_handlers) {
+            RunnerIs::Running => {
+                let Some(waker) = cancellation_handlers.waker() else {
+                    return RunnerIs::Running;
+                };
+                waker.wake_on_resource_change::<State<S>>(world);
+                RunnerIs::Sleeping
+            }
+            other => other,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -15,17 +15,37 @@ use bevy::app::{App, Plugin};
 
 pub mod action;
 pub mod runner;
+#[cfg(feature = "serialize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+pub mod script;
 pub mod task;
+#[cfg(any(test, feature = "testing"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
 
 #[allow(missing_docs)]
 pub mod prelude {
     #[cfg(feature = "record")]
     pub use crate::action::record::{
-        extension::{RecordExtension, RequestRedo, RequestUndo},
-        EditRecordResult, Record, Redo, RedoAction, Rollback, Track, Undo, UndoRedoInProgress,
+        extension::{
+            RecordExtension, RecordRequest, RecordRequestCompleted, RecordRequestPolicy,
+            RequestRedo, RequestUndo,
+        },
+        EditRecordError, EditRecordResult, GlobalRecord, Record, Redo, RedoAction, Rollback, Track,
+        Undo, UndoRedoInProgress,
     };
+    #[cfg(all(feature = "side-effect", feature = "tokio"))]
+    pub use crate::action::side_effect::tokio::{FlurxTokioPlugin, FlurxTokioRuntime};
     #[cfg(feature = "side-effect")]
-    pub use crate::action::side_effect::AsyncFunctor;
+    pub use crate::action::side_effect::{AsyncFunctor, CancellationToken};
+    #[cfg(feature = "asset")]
+    pub use crate::script::asset::{
+        ActionScript, ActionScriptAppExt, ActionScriptError, ActionScriptFailed,
+        ActionScriptLoader, ActionScriptNode, ActionScriptPlugin, ActionScriptRegistry,
+        RunningActionScript,
+    };
+    #[cfg(feature = "serialize")]
+    pub use crate::script::{ScriptReactor, ScriptStep};
     pub use crate::{
         action::inspect::{inspect, Inspect},
         action::omit::*,
@@ -35,6 +55,7 @@ pub mod prelude {
         action::switch::*,
         action::through::{through, Through},
         action::wait::Either,
+        action::Emit,
         action::Map,
         action::Remake,
         action::*,
@@ -96,7 +117,7 @@ mod tests {
         })
     }
 
-    #[derive(Default, Eq, PartialEq, Copy, Clone)]
+    #[derive(Default, Debug, Eq, PartialEq, Copy, Clone)]
     pub struct TestAct;
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -27,12 +27,55 @@ pub struct StepReactor {
     pub reactor: Entity,
 }
 
+/// The priority of the [`Reactor`] attached to the same entity.
+///
+/// The runners of the reactors with higher priority are executed first in each schedule.
+/// Reactors without this component have priority `0`,
+/// and reactors with the same priority are executed in the order they were spawned.
+///
+/// This is also used to decide which reactors are deferred when [`FlurxBudget`](crate::prelude::FlurxBudget) is exhausted.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// fn spawn_reactor(mut commands: Commands) {
+///     commands.spawn((
+///         ReactorPriority(10),
+///         Reactor::schedule(|task| async move {
+///             task.will(Update, once::run(|| {})).await;
+///         }),
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect, Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[reflect(Component, Default, Debug, PartialEq, Hash)]
+pub struct ReactorPriority(pub i32);
+
+/// The counter that numbers the reactors in the order they are spawned.
+///
+/// This is stored per world so that the order is deterministic across runs.
+#[derive(Resource, Default)]
+pub(crate) struct ReactorSequence(u64);
+
+impl ReactorSequence {
+    #[inline]
+    pub(crate) fn next(&mut self) -> u64 {
+        self.0 += 1;
+        self.0
+    }
+}
+
 pub(crate) struct ReactorPlugin;
 
 impl Plugin for ReactorPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<StepAllReactors>()
             .register_type::<StepReactor>()
+            .register_type::<ReactorPriority>()
+            .init_resource::<ReactorSequence>()
             .add_observer(trigger_step_reactor)
             .add_observer(trigger_step_all_reactors);
     }
@@ -108,21 +151,28 @@ where
                 };
                 f
             };
+            let sequence = world
+                .get_resource_mut::<ReactorSequence>()
+                .map(|mut sequence| sequence.next())
+                .unwrap_or_default();
             world
                 .commands()
                 .entity(entity)
-                .insert(NativeReactor::schedule(entity, f));
+                .insert(NativeReactor::schedule(entity, sequence, f));
         })
     }
 }
 
 pub(crate) struct NativeReactor {
     pub(crate) scheduler: CoreScheduler<WorldPtr>,
+    /// The order in which the reactor was spawned.
+    pub(crate) sequence: u64,
 }
 
 impl NativeReactor {
     fn schedule<F>(
         entity: Entity,
+        sequence: u64,
         f: impl FnOnce(ReactorTask) -> F + Send + Sync + 'static,
     ) -> NativeReactor
     where
@@ -131,7 +181,10 @@ impl NativeReactor {
         let scheduler = CoreScheduler::schedule(move |task| async move {
             f(ReactorTask { task, entity }).await;
         });
-        Self { scheduler }
+        Self {
+            scheduler,
+            sequence,
+        }
     }
 
     #[inline(always)]
@@ -1,26 +1,38 @@
 //! `Runner` defines what does the actual processing of the action.
 
-use crate::reactor::{NativeReactor, StepReactor};
+use crate::reactor::{NativeReactor, ReactorPriority, StepReactor};
 use crate::runner::app_schedule_labels::AppScheduleLabels;
+pub use crate::runner::budget::FlurxBudget;
 pub use crate::runner::cancellation_handlers::{CancellationHandlers, CancellationId};
+use crate::runner::parallel::ParallelRunners;
 use crate::runner::reserve_register_runner::{ReserveRegisterRunnerPlugin, ReservedRunner};
-use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleConfigs, ScheduleLabel};
+use bevy::ecs::system::ScheduleSystem;
 use bevy::platform::collections::{HashMap, HashSet};
 use bevy::prelude::*;
 use core::marker::PhantomData;
 pub use output::Output;
+pub use run_action::{ActionFinished, RunActionExt, StandaloneAction};
 use serde::*;
+pub use waker::RunnerWaker;
+use waker::{WakeInterests, WakeQueue};
 
 mod app_schedule_labels;
+mod budget;
 mod cancellation_handlers;
 mod output;
+pub(crate) mod parallel;
 mod reserve_register_runner;
+mod run_action;
+pub(crate) mod scoped;
+pub(crate) mod waker;
 
 pub(crate) struct RunnerPlugin;
 
 impl Plugin for RunnerPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<RunnerIs>()
+            .register_type::<FlurxBudget>()
             .add_plugins(ReserveRegisterRunnerPlugin)
             .add_systems(
                 PreStartup,
@@ -57,6 +69,11 @@ pub enum RunnerIs {
     Completed,
     /// Interrupts the process of the reactor this runner belongs to, as well as the runner itself.
     Canceled,
+    /// The runner's process is not yet complete, and it is waiting to be woken.
+    ///
+    /// The runner will not run again until [`RunnerWaker::wake`] is called.
+    /// The waker can be obtained from [`CancellationHandlers::waker`].
+    Sleeping,
 }
 
 impl RunnerIs {
@@ -71,6 +88,25 @@ impl RunnerIs {
     pub const fn is_cancel(&self) -> bool {
         matches!(self, RunnerIs::Canceled)
     }
+
+    /// Returns whether a runner is sleeping.
+    #[inline(always)]
+    pub const fn is_sleeping(&self) -> bool {
+        matches!(self, RunnerIs::Sleeping)
+    }
+
+    /// Returns [`RunnerIs::Sleeping`] if `all_sleeping` is true, otherwise [`RunnerIs::Running`].
+    ///
+    /// This is used by runners combining multiple runners,
+    /// which can sleep only if all of their unfinished runners are sleeping.
+    #[inline(always)]
+    pub(crate) const fn pending(all_sleeping: bool) -> RunnerIs {
+        if all_sleeping {
+            RunnerIs::Sleeping
+        } else {
+            RunnerIs::Running
+        }
+    }
 }
 
 /// The structure that implements [`Runner`] is given [`Output`],
@@ -123,15 +159,134 @@ impl Runner for BoxedRunner {
     }
 }
 
-#[repr(transparent)]
+/// The runners of the reactors running in the schedule `L`.
+///
+/// The second field holds the runners detached with [`RunnerWaker::detach`]
+/// that have not been moved to [`ReactorRunners::detached`] yet,
+/// since the runners of the reactors are taken out of the registry while running.
 struct RunnersRegistry<L: Send + Sync>(
-    HashMap<Entity, (Vec<BoxedRunner>, CancellationHandlers)>,
+    HashMap<Entity, ReactorRunners>,
+    HashMap<Entity, Vec<BoxedRunner>>,
     PhantomData<L>,
 );
 
 impl<L: Send + Sync> Default for RunnersRegistry<L> {
     fn default() -> Self {
-        Self(HashMap::default(), PhantomData)
+        Self(HashMap::default(), HashMap::default(), PhantomData)
+    }
+}
+
+impl<L: Send + Sync> RunnersRegistry<L> {
+    #[inline]
+    fn take_detached(&mut self, reactor_entity: Entity) -> Vec<BoxedRunner> {
+        self.1.remove(&reactor_entity).unwrap_or_default()
+    }
+}
+
+/// The runners of a reactor.
+struct ReactorRunners {
+    runners: Vec<BoxedRunner>,
+    /// The runners that returned [`RunnerIs::Sleeping`]; they are moved to `runners` when woken.
+    sleeping: Vec<BoxedRunner>,
+    cancellation_handlers: CancellationHandlers,
+    /// The runners detached from the runners above with [`RunnerWaker::detach`].
+    ///
+    /// They are run until they complete, but their completion doesn't step the reactor.
+    detached: Vec<BoxedRunner>,
+    /// The detached runners that returned [`RunnerIs::Sleeping`]; they are moved to `detached` when woken.
+    detached_sleeping: Vec<BoxedRunner>,
+    /// Whether the runners were deferred in the previous frame because [`FlurxBudget`] was exhausted.
+    deferred: bool,
+    /// The order in which the reactor was spawned; see [`NativeReactor::sequence`].
+    sequence: u64,
+}
+
+impl ReactorRunners {
+    fn new(waker: RunnerWaker, sequence: u64) -> Self {
+        Self {
+            runners: Vec::new(),
+            sleeping: Vec::new(),
+            detached: Vec::new(),
+            detached_sleeping: Vec::new(),
+            cancellation_handlers: CancellationHandlers::with_waker(waker),
+            deferred: false,
+            sequence,
+        }
+    }
+
+    /// Returns true if the reactor has runners to run in this frame.
+    fn is_active(&self) -> bool {
+        !self.runners.is_empty() || !self.detached.is_empty()
+    }
+
+    /// Moves the sleeping runners to the runners to run in this frame.
+    fn wake(&mut self) {
+        let sleeping = core::mem::take(&mut self.sleeping);
+        self.runners.extend(sleeping);
+        let detached_sleeping = core::mem::take(&mut self.detached_sleeping);
+        self.detached.extend(detached_sleeping);
+    }
+
+    /// Runs the detached runners, and returns true if one of them was canceled.
+    fn run_detached(&mut self, world: &mut World) -> bool {
+        for mut runner in core::mem::take(&mut self.detached) {
+            match runner.run(world, &mut self.cancellation_handlers) {
+                RunnerIs::Completed => {}
+                RunnerIs::Canceled => return true,
+                RunnerIs::Sleeping => self.detached_sleeping.push(runner),
+                RunnerIs::Running => self.detached.push(runner),
+            }
+        }
+        false
+    }
+
+    fn push(&mut self, runner: BoxedRunner, runner_is: RunnerIs) {
+        if runner_is.is_sleeping() {
+            self.sleeping.push(runner);
+        } else {
+            self.runners.push(runner);
+        }
+    }
+}
+
+/// The queue of the reactors woken by [`RunnerWaker`], and the runners evaluated in parallel.
+///
+/// This is separated from [`RunnersRegistry`] since the registry is taken while running.
+#[derive(Resource)]
+struct RunnersWakeQueue<L: Send + Sync>(WakeQueue, ParallelRunners, PhantomData<L>);
+
+impl<L: Send + Sync> Default for RunnersWakeQueue<L> {
+    fn default() -> Self {
+        Self(
+            WakeQueue::default(),
+            ParallelRunners::default(),
+            PhantomData,
+        )
+    }
+}
+
+fn runner_waker<L: Send + Sync + 'static>(world: &World, reactor_entity: Entity) -> RunnerWaker {
+    let queue = world.resource::<RunnersWakeQueue<L>>();
+    RunnerWaker::new(
+        reactor_entity,
+        queue.0.clone(),
+        queue.1.clone(),
+        detach_runner::<L>,
+    )
+}
+
+/// Passes `runner` to the runners of the reactor running in the schedule `L`; see [`RunnerWaker::detach`].
+fn detach_runner<L: Send + Sync + 'static>(
+    world: &mut World,
+    reactor_entity: Entity,
+    runner: BoxedRunner,
+) {
+    let Some(mut registry) = world.get_non_send_resource_mut::<RunnersRegistry<L>>() else {
+        return;
+    };
+    match registry.0.get_mut(&reactor_entity) {
+        Some(reactor_runners) => reactor_runners.detached.push(runner),
+        None => registry.1.entry(reactor_entity).or_default().push(runner),
     }
 }
 
@@ -146,12 +301,9 @@ pub(crate) fn initialize_runner<Label>(
 ) where
     Label: ScheduleLabel,
 {
-    observe_remove_reactor::<Label>(reactor_entity, world);
-    let label = label.intern();
-    let (running_on_target, contains_label) = register_app_schedule_labels(world, label);
-    add_runner_system_into_schedules::<Label>(world, label, contains_label);
+    let running_on_target = prepare_runner_system(world, label, reactor_entity);
     let runner_is = init_runner::<Label>(world, &mut runner, reactor_entity, running_on_target);
-    push_runner_into_registry::<Label>(world, reactor_entity, runner);
+    push_runner_into_registry::<Label>(world, reactor_entity, runner, runner_is);
     match runner_is {
         RunnerIs::Completed => {
             world.trigger(StepReactor {
@@ -165,6 +317,32 @@ pub(crate) fn initialize_runner<Label>(
     }
 }
 
+/// Registers `runner` without running it.
+///
+/// The runner is run when the runner system of `label` runs next.
+pub(crate) fn register_runner<Label>(
+    world: &mut World,
+    label: &Label,
+    reactor_entity: Entity,
+    runner: BoxedRunner,
+) where
+    Label: ScheduleLabel,
+{
+    prepare_runner_system(world, label, reactor_entity);
+    push_runner_into_registry::<Label>(world, reactor_entity, runner, RunnerIs::Running);
+}
+
+fn prepare_runner_system<Label>(world: &mut World, label: &Label, reactor_entity: Entity) -> bool
+where
+    Label: ScheduleLabel,
+{
+    observe_remove_reactor::<Label>(reactor_entity, world);
+    let label = label.intern();
+    let (running_on_target, contains_label) = register_app_schedule_labels(world, label);
+    add_runner_system_into_schedules::<Label>(world, label, contains_label);
+    running_on_target
+}
+
 fn register_app_schedule_labels(world: &mut World, label: InternedScheduleLabel) -> (bool, bool) {
     world.resource_scope(|world, mut schedule_labels: Mut<AppScheduleLabels>| {
         let running_on_target = schedule_labels
@@ -182,11 +360,14 @@ fn add_runner_system_into_schedules<Label: ScheduleLabel>(
 ) {
     if !world.contains_non_send::<RunnersRegistry<Label>>() {
         world.insert_non_send_resource(RunnersRegistry::<Label>::default());
+        if !world.contains_resource::<RunnersWakeQueue<Label>>() {
+            world.init_resource::<RunnersWakeQueue<Label>>();
+        }
         let mut schedules = world
             .remove_resource::<Schedules>()
             .expect("Schedules was not found");
         if let Some(schedule) = schedules.get_mut(label) {
-            schedule.add_systems(run_runners::<Label>);
+            schedule.add_systems(runner_systems::<Label>());
         } else {
             register_runner_system::<Label>(world, &mut schedules, label, contains_label);
         }
@@ -203,13 +384,47 @@ fn register_runner_system<L: ScheduleLabel>(
     if contains_label {
         world.commands().write_message(ReservedRunner {
             label,
-            system: || Box::new(IntoSystem::into_system(run_runners::<L>)),
+            systems: runner_systems::<L>,
         });
     } else {
-        schedules.add_systems(label, run_runners::<L>);
+        schedules.add_systems(label, runner_systems::<L>());
     }
 }
 
+/// The parallel runners are evaluated first so that the runners they wake can complete in the same frame.
+///
+/// The exclusive runners system is skipped if no reactor has runners to run,
+/// so the frames in which all reactors are sleeping don't need an exclusive access to the world.
+fn runner_systems<L: Send + Sync + 'static>() -> ScheduleConfigs<ScheduleSystem> {
+    (
+        evaluate_parallel_runners::<L>,
+        run_runners::<L>.run_if(has_runners_to_run::<L>),
+    )
+        .chain()
+        .into_configs()
+}
+
+fn evaluate_parallel_runners<L: Send + Sync + 'static>(world: &World) {
+    WakeInterests::wake(world);
+    if let Some(queue) = world.get_resource::<RunnersWakeQueue<L>>() {
+        queue.1.evaluate(world);
+    }
+}
+
+fn has_runners_to_run<L: Send + Sync + 'static>(
+    runners_registry: Option<NonSend<RunnersRegistry<L>>>,
+    queue: Option<Res<RunnersWakeQueue<L>>>,
+) -> bool {
+    let woken = queue.is_some_and(|queue| !queue.0.is_empty());
+    woken
+        || runners_registry.is_some_and(|registry| {
+            registry
+                .0
+                .values()
+                .any(|reactor_runners| reactor_runners.is_active())
+        })
+}
+
 /// If the current schedule is the same as the schedule on which the [`BoxedRunner`] is running,
 /// it will be executed immediately.
 fn init_runner<Label: ScheduleLabel>(
@@ -219,14 +434,11 @@ fn init_runner<Label: ScheduleLabel>(
     running_on_target: bool,
 ) -> RunnerIs {
     if running_on_target {
-        let mut handers = CancellationHandlers::default();
+        let mut handers =
+            CancellationHandlers::with_waker(runner_waker::<Label>(world, reactor_entity));
         let runner_is = runner.run(world, &mut handers);
-        world
-            .non_send_resource_mut::<RunnersRegistry<Label>>()
-            .0
-            .entry(reactor_entity)
-            .or_default()
-            .1
+        reactor_runners::<Label>(world, reactor_entity)
+            .cancellation_handlers
             .extend(handers);
         runner_is
     } else {
@@ -239,14 +451,38 @@ fn push_runner_into_registry<Label: ScheduleLabel>(
     world: &mut World,
     reactor_entity: Entity,
     runner: BoxedRunner,
+    runner_is: RunnerIs,
 ) {
+    let detached = world
+        .non_send_resource_mut::<RunnersRegistry<Label>>()
+        .take_detached(reactor_entity);
+    let mut reactor_runners = reactor_runners::<Label>(world, reactor_entity);
+    reactor_runners.push(runner, runner_is);
+    reactor_runners.detached.extend(detached);
+}
+
+fn reactor_runners<Label: ScheduleLabel>(
+    world: &mut World,
+    reactor_entity: Entity,
+) -> Mut<'_, ReactorRunners> {
+    let waker = runner_waker::<Label>(world, reactor_entity);
+    let sequence = world
+        .get::<NativeReactor>(reactor_entity)
+        .map(|reactor| reactor.sequence)
+        .or_else(|| {
+            world
+                .get::<StandaloneAction>(reactor_entity)
+                .map(|action| action.sequence)
+        })
+        .unwrap_or_default();
     world
         .non_send_resource_mut::<RunnersRegistry<Label>>()
-        .0
-        .entry(reactor_entity)
-        .or_default()
-        .0
-        .push(runner);
+        .map_unchanged(|registry| {
+            registry
+                .0
+                .entry(reactor_entity)
+                .or_insert_with(|| ReactorRunners::new(waker, sequence))
+        })
 }
 
 #[inline]
@@ -265,23 +501,24 @@ fn observe_remove_reactor<Label: ScheduleLabel>(reactor_entity: Entity, world: &
         return;
     }
     let mut observer = Observer::new(
-        move |_: On<Remove, NativeReactor>, mut commands: Commands| {
+        move |_: On<Remove, (NativeReactor, StandaloneAction)>, mut commands: Commands| {
             commands.queue(move |world: &mut World| {
                 let Some(mut runner_registry) =
                     world.remove_non_send_resource::<RunnersRegistry<Label>>()
                 else {
                     return;
                 };
-                let Some((_, cancellation_handlers)) = runner_registry.0.remove(&reactor_entity)
-                else {
+                runner_registry.1.remove(&reactor_entity);
+                let Some(reactor_runners) = runner_registry.0.remove(&reactor_entity) else {
                     world.insert_non_send_resource(runner_registry);
                     return;
                 };
-                for handler in cancellation_handlers.0.values() {
+                for handler in reactor_runners.cancellation_handlers.0.values() {
                     handler(world);
                 }
                 if let Some(mut r) = world.get_non_send_resource_mut::<RunnersRegistry<Label>>() {
                     r.0.extend(runner_registry.0);
+                    r.1.extend(runner_registry.1);
                 } else {
                     world.insert_non_send_resource(runner_registry);
                 }
@@ -295,6 +532,35 @@ fn observe_remove_reactor<Label: ScheduleLabel>(reactor_entity: Entity, world: &
     world.spawn(observer);
 }
 
+/// Returns the reactors that have runners to run in descending order of [`ReactorPriority`].
+///
+/// The reactors deferred in the previous frame come first among the same priority,
+/// and then the ties are broken by the order in which the reactors were spawned,
+/// so the order is deterministic across runs.
+fn execution_order(
+    world: &World,
+    runners_registry: &HashMap<Entity, ReactorRunners>,
+) -> Vec<Entity> {
+    let mut order = runners_registry
+        .iter()
+        .filter(|(_, reactor_runners)| reactor_runners.is_active())
+        .map(|(entity, reactor_runners)| {
+            let priority = world
+                .get::<ReactorPriority>(*entity)
+                .copied()
+                .unwrap_or_default();
+            (
+                core::cmp::Reverse(priority),
+                !reactor_runners.deferred,
+                reactor_runners.sequence,
+                *entity,
+            )
+        })
+        .collect::<Vec<_>>();
+    order.sort_unstable();
+    order.into_iter().map(|(_, _, _, entity)| entity).collect()
+}
+
 fn run_runners<L: Send + Sync + 'static>(world: &mut World) -> Result {
     let Some(mut runners_registry) = world
         .get_non_send_resource_mut::<RunnersRegistry<L>>()
@@ -302,37 +568,74 @@ fn run_runners<L: Send + Sync + 'static>(world: &mut World) -> Result {
     else {
         return Ok(());
     };
+    if let Some(queue) = world.get_resource::<RunnersWakeQueue<L>>() {
+        for entity in queue.0.drain() {
+            if let Some(reactor_runners) = runners_registry.get_mut(&entity) {
+                reactor_runners.wake();
+            }
+        }
+    }
 
-    for (entity, (runners, cancellation_handlers)) in runners_registry.iter_mut() {
-        let mut request_cancel = false;
+    let mut budget = world
+        .get_resource::<FlurxBudget>()
+        .copied()
+        .unwrap_or_default()
+        .start();
+    for entity in execution_order(world, &runners_registry) {
+        let Some(reactor_runners) = runners_registry.get_mut(&entity) else {
+            continue;
+        };
+        if budget.exhausted() {
+            reactor_runners.deferred = true;
+            continue;
+        }
+        reactor_runners.deferred = false;
+        budget.consume(reactor_runners.runners.len() + reactor_runners.detached.len());
+        let mut request_cancel = reactor_runners.run_detached(world);
         let mut request_step = false;
-        runners.retain_mut(|runner| {
+        for mut runner in core::mem::take(&mut reactor_runners.runners) {
             if request_cancel {
-                return false;
+                break;
             }
-            match runner.run(world, cancellation_handlers) {
+            match runner.run(world, &mut reactor_runners.cancellation_handlers) {
                 RunnerIs::Completed => {
                     request_step = true;
-                    false
                 }
-                RunnerIs::Running => true,
                 RunnerIs::Canceled => {
                     request_cancel = true;
-                    false
                 }
+                runner_is => reactor_runners.push(runner, runner_is),
             }
-        });
+        }
+        let detached = world
+            .non_send_resource_mut::<RunnersRegistry<L>>()
+            .take_detached(entity);
+        reactor_runners.detached.extend(detached);
         if request_cancel {
-            world.commands().entity(*entity).despawn();
+            world.commands().entity(entity).despawn();
         } else if request_step {
-            world.commands().trigger(StepReactor { reactor: *entity });
+            world.commands().trigger(StepReactor { reactor: entity });
         }
     }
 
+    // The reactors despawned while running could not be found by `observe_remove_reactor`
+    // because the registry was taken, so their cancellation handlers are called here.
+    let (alive, mut despawned): (Vec<_>, Vec<_>) =
+        runners_registry.into_iter().partition(|(entity, _)| {
+            world.get_entity(*entity).is_ok_and(|entity| {
+                entity.contains::<NativeReactor>() || entity.contains::<StandaloneAction>()
+            })
+        });
+    despawned.sort_unstable_by_key(|(_, reactor_runners)| reactor_runners.sequence);
+    for (_, reactor_runners) in despawned {
+        for handler in reactor_runners.cancellation_handlers.0.values() {
+            handler(world);
+        }
+    }
     world
         .non_send_resource_mut::<RunnersRegistry<L>>()
         .0
-        .extend(runners_registry);
+        .extend(alive);
     Ok(())
 }
 
@@ -391,18 +694,22 @@ pub(crate) mod macros {
 #[cfg(test)]
 mod tests {
     use crate::action::{delay, once, wait};
-    use crate::prelude::{ActionSeed, CancellationHandlers, Reactor, Then};
+    use crate::prelude::{
+        ActionSeed, CancellationHandlers, FlurxBudget, Reactor, ReactorPriority, Then,
+    };
     use crate::reactor::NativeReactor;
-    use crate::runner::{ReactorScheduleLabel, Runner, RunnerIs};
-    use crate::test_util::test;
+    use crate::runner::{Output, ReactorScheduleLabel, Runner, RunnerIs};
+    use crate::testing;
     use crate::tests::test_app;
+    use alloc::vec;
+    use alloc::vec::Vec;
     use bevy::app::{AppExit, PostUpdate, Startup};
     use bevy::ecs::message::MessageCursor;
     use bevy::ecs::system::RunSystemOnce;
     use bevy::prelude::{
         Commands, Component, Entity, IntoScheduleConfigs, Query, ResMut, Update, World,
     };
-    use bevy::prelude::{Resource, With};
+    use bevy::prelude::{DetectChangesMut, Resource, With};
     use bevy_test_helper::event::DirectEvents;
     use bevy_test_helper::resource::bool::BoolExtension;
     use bevy_test_helper::resource::count::Count;
@@ -478,7 +785,7 @@ mod tests {
                 task.will(
                     Update,
                     wait::both(
-                        test::cancel(),
+                        testing::cancel(),
                         wait::until(|mut count: ResMut<Count>| {
                             count.increment();
                             false
@@ -711,4 +1018,276 @@ mod tests {
         app.update();
         app.assert_message_comes(&mut cursor);
     }
+
+    #[derive(Resource)]
+    struct SleepingWaker(crate::prelude::RunnerWaker);
+
+    struct SleepRunner {
+        wake_count: usize,
+        output: Output<()>,
+    }
+
+    impl Runner for SleepRunner {
+        fn run(
+            &mut self,
+            world: &mut World,
+            cancellation_handlers: &mut CancellationHandlers,
+        ) -> RunnerIs {
+            world.resource_mut::<Count>().increment();
+            if self.wake_count <= world.resource::<Count>().0 {
+                self.output.set(());
+                return RunnerIs::Completed;
+            }
+            let waker = cancellation_handlers.waker().unwrap();
+            world.insert_resource(SleepingWaker(waker));
+            RunnerIs::Sleeping
+        }
+    }
+
+    fn sleep_action() -> ActionSeed {
+        ActionSeed::new(|_, output| SleepRunner {
+            wake_count: 3,
+            output,
+        })
+    }
+
+    #[test]
+    fn sleeping_runner_runs_only_when_woken() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::both(sleep_action(), sleep_action()))
+                    .await;
+                task.will(Update, once::res::insert().with(Count(100)))
+                    .await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(2));
+        for _ in 0..3 {
+            app.update();
+            app.assert_resource_eq(Count(2));
+        }
+        app.world().resource::<SleepingWaker>().0.wake();
+        app.update();
+        app.assert_resource_eq(Count(100));
+    }
+
+    #[derive(Resource, Component, Default)]
+    struct Watched;
+
+    /// Counts its runs, and sleeps until [`Watched`] of the entity, or the resource if `None`, changes.
+    struct WatchRunner(Option<Entity>);
+
+    impl Runner for WatchRunner {
+        fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+            world.resource_mut::<Count>().increment();
+            let waker = token.waker().unwrap();
+            match self.0 {
+                Some(entity) => waker.wake_on_component_change::<Watched>(world, entity),
+                None => waker.wake_on_resource_change::<Watched>(world),
+            }
+            RunnerIs::Sleeping
+        }
+    }
+
+    #[test]
+    fn wake_on_resource_change() {
+        let mut app = test_app();
+        app.init_resource::<Watched>();
+        app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, ActionSeed::new(|_, _| WatchRunner(None)))
+                .await;
+        }));
+        app.update();
+        app.update();
+        let runs = app.world().resource::<Count>().0;
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(runs));
+
+        app.world_mut().resource_mut::<Watched>().set_changed();
+        app.update();
+        app.assert_resource_eq(Count(runs + 1));
+        app.update();
+        app.assert_resource_eq(Count(runs + 1));
+    }
+
+    #[test]
+    fn wake_on_component_change() {
+        let mut app = test_app();
+        let entity = app.world_mut().spawn(Watched).id();
+        app.world_mut()
+            .spawn(Reactor::schedule(move |task| async move {
+                task.will(
+                    Update,
+                    ActionSeed::new(move |_, _| WatchRunner(Some(entity))),
+                )
+                .await;
+            }));
+        app.update();
+        app.update();
+        let runs = app.world().resource::<Count>().0;
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(runs));
+
+        app.world_mut()
+            .entity_mut(entity)
+            .get_mut::<Watched>()
+            .unwrap()
+            .set_changed();
+        app.update();
+        app.assert_resource_eq(Count(runs + 1));
+
+        app.world_mut().entity_mut(entity).remove::<Watched>();
+        app.update();
+        app.assert_resource_eq(Count(runs + 2));
+    }
+
+    #[derive(Resource, Default)]
+    struct ExecutionOrder(Vec<i32>);
+
+    fn spawn_priority_reactors(mut commands: Commands) {
+        for priority in [0, 10, -5] {
+            commands.spawn((
+                ReactorPriority(priority),
+                Reactor::schedule(move |task| async move {
+                    task.will(
+                        Update,
+                        once::run(move |mut order: ResMut<ExecutionOrder>| {
+                            order.0.push(priority);
+                        }),
+                    )
+                    .await;
+                }),
+            ));
+        }
+    }
+
+    #[test]
+    fn run_in_descending_order_of_priority() {
+        let mut app = test_app();
+        app.init_resource::<ExecutionOrder>()
+            .add_systems(Startup, spawn_priority_reactors);
+        app.update();
+        assert_eq!(app.world().resource::<ExecutionOrder>().0, vec![10, 0, -5]);
+    }
+
+    #[test]
+    fn defer_low_priority_reactors_if_budget_exhausted() {
+        let mut app = test_app();
+        app.init_resource::<ExecutionOrder>()
+            .insert_resource(FlurxBudget {
+                max_runners_per_frame: Some(1),
+                max_time: None,
+            })
+            .add_systems(Startup, spawn_priority_reactors);
+        app.update();
+        assert_eq!(app.world().resource::<ExecutionOrder>().0, vec![10]);
+        app.update();
+        assert_eq!(app.world().resource::<ExecutionOrder>().0, vec![10, 0]);
+        app.update();
+        assert_eq!(app.world().resource::<ExecutionOrder>().0, vec![10, 0, -5]);
+    }
+
+    #[test]
+    fn deferred_reactors_run_first_among_same_priority() {
+        let mut app = test_app();
+        app.init_resource::<ExecutionOrder>()
+            .insert_resource(FlurxBudget {
+                max_runners_per_frame: Some(1),
+                max_time: None,
+            })
+            .add_systems(Startup, |mut commands: Commands| {
+                for id in [1, 2] {
+                    commands.spawn(Reactor::schedule(move |task| async move {
+                        task.will(
+                            Update,
+                            wait::until(move |mut order: ResMut<ExecutionOrder>| {
+                                order.0.push(id);
+                                false
+                            }),
+                        )
+                        .await;
+                    }));
+                }
+            });
+        for _ in 0..4 {
+            app.update();
+        }
+        let order = &app.world().resource::<ExecutionOrder>().0;
+        assert_eq!(order.len(), 4);
+        assert!(order.windows(2).all(|ids| ids[0] != ids[1]));
+    }
+
+    #[test]
+    fn run_in_spawn_order_among_same_priority() {
+        let mut app = test_app();
+        // Recycle an entity so that the entity order differs from the spawn order.
+        let recycled = app.world_mut().spawn_empty().id();
+        app.world_mut().despawn(recycled);
+        app.init_resource::<ExecutionOrder>()
+            .add_systems(Startup, |mut commands: Commands| {
+                for id in 0..3 {
+                    commands.spawn(Reactor::schedule(move |task| async move {
+                        task.will(
+                            Update,
+                            once::run(move |mut order: ResMut<ExecutionOrder>| {
+                                order.0.push(id);
+                            }),
+                        )
+                        .await;
+                    }));
+                }
+            });
+        app.update();
+        assert_eq!(app.world().resource::<ExecutionOrder>().0, vec![0, 1, 2]);
+    }
+
+    struct RegisterHandlersRunner;
+
+    impl Runner for RegisterHandlersRunner {
+        fn run(
+            &mut self,
+            _: &mut World,
+            cancellation_handlers: &mut CancellationHandlers,
+        ) -> RunnerIs {
+            if cancellation_handlers.0.is_empty() {
+                for id in 0..5 {
+                    cancellation_handlers.register(move |world: &mut World| {
+                        world.resource_mut::<ExecutionOrder>().0.push(id);
+                    });
+                }
+            }
+            RunnerIs::Running
+        }
+    }
+
+    #[test]
+    fn call_cancellation_handlers_in_registration_order() {
+        let mut app = test_app();
+        app.init_resource::<ExecutionOrder>()
+            .add_systems(Startup, |mut commands: Commands| {
+                commands.spawn(Reactor::schedule(|task| async move {
+                    task.will(
+                        Update,
+                        ActionSeed::<(), ()>::new(|_, _| RegisterHandlersRunner),
+                    )
+                    .await;
+                }));
+            });
+        app.update();
+        let reactor = app
+            .world_mut()
+            .query_filtered::<Entity, With<NativeReactor>>()
+            .single(app.world())
+            .unwrap();
+        app.world_mut().despawn(reactor);
+        app.update();
+        assert_eq!(
+            app.world().resource::<ExecutionOrder>().0,
+            vec![0, 1, 2, 3, 4]
+        );
+    }
 }
@@ -0,0 +1,80 @@
+use bevy::platform::time::Instant;
+use bevy::prelude::{Reflect, ReflectDefault, ReflectResource, Resource};
+use core::time::Duration;
+
+/// Limits the amount of the runners executed per frame in each schedule.
+///
+/// When the budget is exhausted, the remaining reactors are deferred to the next frame.
+/// Reactors are executed in descending order of [`ReactorPriority`](crate::prelude::ReactorPriority),
+/// so the reactors with low priority are deferred first.
+/// Reactors deferred in the previous frame are executed before the others with the same priority,
+/// so they are not starved by the reactors with the same priority.
+///
+/// The budget is checked before executing each reactor, and at least one reactor is executed per frame.
+///
+/// The runners executed immediately because they are registered in the schedule currently running
+/// are not limited by the budget.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use core::time::Duration;
+///
+/// App::new()
+///     .add_plugins((MinimalPlugins, FlurxPlugin))
+///     .insert_resource(FlurxBudget {
+///         max_runners_per_frame: Some(100),
+///         max_time: Some(Duration::from_millis(2)),
+///     });
+/// ```
+#[derive(Resource, Reflect, Debug, Default, Clone, Copy, Eq, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct FlurxBudget {
+    /// The maximum number of the runners executed per frame in each schedule.
+    pub max_runners_per_frame: Option<usize>,
+
+    /// The maximum time spent executing the runners per frame in each schedule.
+    ///
+    /// In `no_std` environments without a default clock,
+    /// [`Instant::set_elapsed`] must be called to use this.
+    pub max_time: Option<Duration>,
+}
+
+impl FlurxBudget {
+    pub(crate) fn start(self) -> BudgetTracker {
+        BudgetTracker {
+            started_at: self.max_time.map(|_| Instant::now()),
+            budget: self,
+            runners: 0,
+        }
+    }
+}
+
+pub(crate) struct BudgetTracker {
+    budget: FlurxBudget,
+    started_at: Option<Instant>,
+    runners: usize,
+}
+
+impl BudgetTracker {
+    #[inline]
+    pub(crate) fn consume(&mut self, runners: usize) {
+        self.runners += runners;
+    }
+
+    pub(crate) fn exhausted(&self) -> bool {
+        if self.runners == 0 {
+            return false;
+        }
+        self.budget
+            .max_runners_per_frame
+            .is_some_and(|max| max <= self.runners)
+            || self
+                .budget
+                .max_time
+                .zip(self.started_at)
+                .is_some_and(|(max, started_at)| max <= started_at.elapsed())
+    }
+}
@@ -1,40 +1,106 @@
-use bevy::platform::collections::HashMap;
+use crate::runner::RunnerWaker;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
 use bevy::prelude::{Component, Reflect, World};
 use core::sync::atomic::{AtomicU64, Ordering};
 
 /// The cancellation handler id assigned by [`CancellationHandlers`].
 ///
 /// For unregister the handler, call [`CancellationHandlers::unregister`] with this id.
+///
+/// Ids are assigned in ascending order of registration.
 #[repr(transparent)]
-#[derive(Default, Debug, Eq, PartialEq, Hash, Copy, Clone, Reflect)]
+#[derive(Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Reflect)]
 pub struct CancellationId(u64);
 
 /// Structure for canceling a [`Reactor`](crate::prelude::Reactor).
 ///
 /// This is passed as argument in [`Runner::run`](crate::prelude::Runner::run),
 /// and the [`Reactor`](crate::prelude::Reactor) can be cancelled by despawning the entity to which it is attached.
-#[repr(transparent)]
+///
+/// When the [`Reactor`](crate::prelude::Reactor) is cancelled, the handlers are called in the order they were registered.
+///
+/// It also provides [`RunnerWaker`] to wake the runners returning [`RunnerIs::Sleeping`](crate::prelude::RunnerIs::Sleeping).
 #[derive(Default, Component)]
-pub struct CancellationHandlers(pub(crate) HashMap<CancellationId, fn(&mut World)>);
+pub struct CancellationHandlers(
+    pub(crate) BTreeMap<CancellationId, Box<dyn Fn(&mut World) + Send + Sync>>,
+    Option<RunnerWaker>,
+);
 
 impl CancellationHandlers {
+    #[inline]
+    pub(crate) fn with_waker(waker: RunnerWaker) -> Self {
+        Self(BTreeMap::new(), Some(waker))
+    }
+
+    /// Returns the waker of the reactor running the runner.
+    ///
+    /// Returns `None` if the runner isn't run by a reactor, such as in a unit test.
+    /// In that case, the runner shouldn't return [`RunnerIs::Sleeping`](crate::prelude::RunnerIs::Sleeping)
+    /// since there is no one to wake it.
+    #[inline]
+    pub fn waker(&self) -> Option<RunnerWaker> {
+        self.1.clone()
+    }
+
     /// Register a function that will be called when [`CancellationHandlers`] is cancelled.
+    ///
+    /// The function can capture its environment, such as a handle of the task to be aborted.
     #[inline]
-    pub fn register(&mut self, f: fn(&mut World)) -> CancellationId {
+    pub fn register(&mut self, f: impl Fn(&mut World) + Send + Sync + 'static) -> CancellationId {
         static ID: AtomicU64 = AtomicU64::new(0);
         let id = CancellationId(ID.fetch_add(1, Ordering::Relaxed));
-        self.0.insert(id, f);
+        self.0.insert(id, Box::new(f));
         id
     }
 
+    /// Returns [`RunnerIs::Sleeping`](crate::prelude::RunnerIs::Sleeping) if the runner can be woken,
+    /// otherwise [`RunnerIs::Running`](crate::prelude::RunnerIs::Running).
+    #[cfg(feature = "side-effect")]
+    #[inline]
+    pub(crate) fn pending(&self) -> crate::prelude::RunnerIs {
+        crate::prelude::RunnerIs::pending(self.1.is_some())
+    }
+
+    /// Polls the `future` once with the waker of the reactor,
+    /// so that the reactor is woken when the future can make progress.
+    #[cfg(feature = "side-effect")]
+    pub(crate) fn poll<F: core::future::Future + Unpin>(
+        &self,
+        future: &mut F,
+    ) -> core::task::Poll<F::Output> {
+        use core::task::{Context, Waker};
+        let waker = self
+            .1
+            .clone()
+            .map_or_else(|| Waker::noop().clone(), Waker::from);
+        core::pin::Pin::new(future).poll(&mut Context::from_waker(&waker))
+    }
+
     /// Unregister a cancellation handler related to [`CancellationId`].
     #[inline]
     pub fn unregister(&mut self, id: &CancellationId) {
         self.0.remove(id);
     }
 
+    /// Creates empty handlers sharing the waker with `self`.
+    #[inline]
+    pub(crate) fn scope(&self) -> Self {
+        Self(BTreeMap::new(), self.1.clone())
+    }
+
+    /// Calls and removes all handlers in the order they were registered.
+    pub(crate) fn cancel(&mut self, world: &mut World) {
+        for handler in core::mem::take(&mut self.0).into_values() {
+            handler(world);
+        }
+    }
+
     #[inline]
     pub(crate) fn extend(&mut self, other: Self) {
         self.0.extend(other.0);
+        if self.1.is_none() {
+            self.1 = other.1;
+        }
     }
 }
@@ -0,0 +1,38 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use bevy::platform::sync::Mutex;
+use bevy::prelude::World;
+use core::fmt::{Debug, Formatter};
+
+/// A runner evaluated in the non-exclusive system with a shared reference to the world.
+pub(crate) trait ParallelRunner: Send {
+    /// Evaluates the runner, and returns true if it no longer needs to be evaluated.
+    fn evaluate(&mut self, world: &World) -> bool;
+}
+
+/// The runners evaluated with a shared reference to the world before the exclusive runners system.
+///
+/// The runners wake their reactor when they finish, and then the exclusive runners take over.
+#[derive(Clone, Default)]
+pub(crate) struct ParallelRunners(Arc<Mutex<Vec<Box<dyn ParallelRunner>>>>);
+
+impl ParallelRunners {
+    pub(crate) fn push(&self, runner: impl ParallelRunner + 'static) {
+        if let Ok(mut runners) = self.0.lock() {
+            runners.push(Box::new(runner));
+        }
+    }
+
+    pub(crate) fn evaluate(&self, world: &World) {
+        if let Ok(mut runners) = self.0.lock() {
+            runners.retain_mut(|runner| !runner.evaluate(world));
+        }
+    }
+}
+
+impl Debug for ParallelRunners {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ParallelRunners").finish_non_exhaustive()
+    }
+}
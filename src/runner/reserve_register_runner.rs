@@ -1,5 +1,5 @@
 use bevy::app::{App, Last, Plugin};
-use bevy::ecs::schedule::InternedScheduleLabel;
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleConfigs};
 use bevy::ecs::system::ScheduleSystem;
 use bevy::prelude::*;
 use itertools::Itertools;
@@ -20,7 +20,7 @@ impl Plugin for ReserveRegisterRunnerPlugin {
 #[derive(Message, Debug)]
 pub(crate) struct ReservedRunner {
     pub label: InternedScheduleLabel,
-    pub system: fn() -> ScheduleSystem,
+    pub systems: fn() -> ScheduleConfigs<ScheduleSystem>,
 }
 
 fn register_runner_system(
@@ -28,6 +28,6 @@ fn register_runner_system(
     mut schedules: ResMut<Schedules>,
 ) {
     for event in events.read().unique_by(|e| e.label) {
-        schedules.add_systems(event.label, (event.system)());
+        schedules.add_systems(event.label, (event.systems)());
     }
 }
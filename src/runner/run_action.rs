@@ -0,0 +1,437 @@
+use crate::action::Action;
+use crate::prelude::{CancellationHandlers, Output};
+use crate::reactor::ReactorSequence;
+use crate::runner::{register_runner, BoxedRunner, Runner, RunnerIs};
+use alloc::boxed::Box;
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::{
+    Commands, Component, Entity, EntityCommands, EntityEvent, EntityWorldMut, In, IntoSystem, World,
+};
+
+/// The component attached to the entities spawned by [`RunActionExt`].
+///
+/// The entity has only the runner of the action and no [`Reactor`](crate::prelude::Reactor),
+/// and it is despawned after the action has completed.
+/// Despawn the entity if you want to cancel the action.
+#[derive(Component, Debug)]
+pub struct StandaloneAction {
+    pub(crate) sequence: u64,
+}
+
+/// The event triggered on the entity spawned by [`RunActionExt::run_action`] when the action completes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// fn wait_frames(mut commands: Commands) {
+///     commands
+///         .run_action(Update, delay::frames().with(3).map(|_| 3_usize))
+///         .observe(|finished: On<ActionFinished<usize>>| {
+///             info!("waited {} frames", finished.output);
+///         });
+/// }
+/// ```
+#[derive(EntityEvent, Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ActionFinished<O: Send + Sync + 'static> {
+    /// The entity that ran the action.
+    #[event_target]
+    pub entity: Entity,
+
+    /// The output of the action.
+    pub output: O,
+}
+
+/// Runs an [`Action`] without [`Reactor`](crate::prelude::Reactor).
+///
+/// This spawns an entity that has only the runner of the action, so it doesn't have
+/// the overhead of the future and scheduler of a reactor.
+/// It is useful to run a single action from an ordinary system.
+///
+/// Unlike the runners of reactors, the runner is never run immediately;
+/// it's run the next time the runner system of the schedule runs,
+/// so observers can be added to the returned entity before the action completes.
+pub trait RunActionExt {
+    /// The entity returned from the methods.
+    type EntityMut<'a>
+    where
+        Self: 'a;
+
+    /// Runs `action` in `schedule`.
+    ///
+    /// [`ActionFinished`] is triggered on the returned entity when the action completes,
+    /// and then the entity is despawned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// fn exit_after_frames(mut commands: Commands) {
+    ///     commands.run_action(
+    ///         Update,
+    ///         delay::frames()
+    ///             .with(30)
+    ///             .then(once::message::app_exit_success()),
+    ///     );
+    /// }
+    /// ```
+    fn run_action<L, I, O>(
+        &mut self,
+        schedule: L,
+        action: impl Into<Action<I, O>> + Send + 'static,
+    ) -> Self::EntityMut<'_>
+    where
+        L: ScheduleLabel,
+        I: 'static,
+        O: Send + Sync + 'static;
+
+    /// Runs `action` in `schedule`, and runs `callback` with the output when the action completes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// fn wait_input(mut commands: Commands) {
+    ///     commands.run_action_with(
+    ///         Update,
+    ///         wait::input::just_pressed().with(KeyCode::KeyA),
+    ///         |_: In<()>, mut commands: Commands| {
+    ///             commands.spawn(Name::new("A"));
+    ///         },
+    ///     );
+    /// }
+    /// ```
+    fn run_action_with<L, I, O, M>(
+        &mut self,
+        schedule: L,
+        action: impl Into<Action<I, O>> + Send + 'static,
+        callback: impl IntoSystem<In<O>, (), M> + Send + 'static,
+    ) -> Self::EntityMut<'_>
+    where
+        L: ScheduleLabel,
+        I: 'static,
+        O: 'static;
+}
+
+impl RunActionExt for Commands<'_, '_> {
+    type EntityMut<'a>
+        = EntityCommands<'a>
+    where
+        Self: 'a;
+
+    fn run_action<L, I, O>(
+        &mut self,
+        schedule: L,
+        action: impl Into<Action<I, O>> + Send + 'static,
+    ) -> Self::EntityMut<'_>
+    where
+        L: ScheduleLabel,
+        I: 'static,
+        O: Send + Sync + 'static,
+    {
+        let entity = self.spawn_empty().id();
+        self.queue(move |world: &mut World| {
+            spawn_runner(world, entity, schedule, action.into(), trigger_finished);
+        });
+        self.entity(entity)
+    }
+
+    fn run_action_with<L, I, O, M>(
+        &mut self,
+        schedule: L,
+        action: impl Into<Action<I, O>> + Send + 'static,
+        callback: impl IntoSystem<In<O>, (), M> + Send + 'static,
+    ) -> Self::EntityMut<'_>
+    where
+        L: ScheduleLabel,
+        I: 'static,
+        O: 'static,
+    {
+        let entity = self.spawn_empty().id();
+        self.queue(move |world: &mut World| {
+            spawn_runner(
+                world,
+                entity,
+                schedule,
+                action.into(),
+                run_callback(callback),
+            );
+        });
+        self.entity(entity)
+    }
+}
+
+impl RunActionExt for World {
+    type EntityMut<'a>
+        = EntityWorldMut<'a>
+    where
+        Self: 'a;
+
+    fn run_action<L, I, O>(
+        &mut self,
+        schedule: L,
+        action: impl Into<Action<I, O>> + Send + 'static,
+    ) -> Self::EntityMut<'_>
+    where
+        L: ScheduleLabel,
+        I: 'static,
+        O: Send + Sync + 'static,
+    {
+        let entity = self.spawn_empty().id();
+        spawn_runner(self, entity, schedule, action.into(), trigger_finished);
+        self.entity_mut(entity)
+    }
+
+    fn run_action_with<L, I, O, M>(
+        &mut self,
+        schedule: L,
+        action: impl Into<Action<I, O>> + Send + 'static,
+        callback: impl IntoSystem<In<O>, (), M> + Send + 'static,
+    ) -> Self::EntityMut<'_>
+    where
+        L: ScheduleLabel,
+        I: 'static,
+        O: 'static,
+    {
+        let entity = self.spawn_empty().id();
+        spawn_runner(
+            self,
+            entity,
+            schedule,
+            action.into(),
+            run_callback(callback),
+        );
+        self.entity_mut(entity)
+    }
+}
+
+type OnFinished<O> = Box<dyn FnOnce(&mut World, Entity, O)>;
+
+fn trigger_finished<O: Send + Sync + 'static>(world: &mut World, entity: Entity, output: O) {
+    world.trigger(ActionFinished { entity, output });
+}
+
+fn run_callback<O: 'static, M>(
+    callback: impl IntoSystem<In<O>, (), M> + 'static,
+) -> impl FnOnce(&mut World, Entity, O) + 'static {
+    move |world, _, output| {
+        let _ = world.run_system_once_with(callback, output);
+    }
+}
+
+fn spawn_runner<L, I, O>(
+    world: &mut World,
+    entity: Entity,
+    schedule: L,
+    action: Action<I, O>,
+    on_finished: impl FnOnce(&mut World, Entity, O) + 'static,
+) where
+    L: ScheduleLabel,
+    I: 'static,
+    O: 'static,
+{
+    if world.get_entity(entity).is_err() {
+        return;
+    }
+    let sequence = world.get_resource_or_init::<ReactorSequence>().next();
+    world
+        .entity_mut(entity)
+        .insert(StandaloneAction { sequence });
+    let output = Output::default();
+    let runner = StandaloneRunner {
+        runner: action.create_runner(output.clone()),
+        output,
+        entity,
+        on_finished: Some(Box::new(on_finished)),
+    };
+    register_runner(world, &schedule, entity, BoxedRunner::new(runner));
+}
+
+struct StandaloneRunner<O> {
+    runner: BoxedRunner,
+    output: Output<O>,
+    entity: Entity,
+    on_finished: Option<OnFinished<O>>,
+}
+
+impl<O> Runner for StandaloneRunner<O> {
+    fn run(
+        &mut self,
+        world: &mut World,
+        cancellation_handlers: &mut CancellationHandlers,
+    ) -> RunnerIs {
+        let runner_is = self.runner.run(world, cancellation_handlers);
+        if runner_is.is_completed() {
+            if let Some((on_finished, output)) = self.on_finished.take().zip(self.output.take()) {
+                on_finished(world, self.entity, output);
+            }
+            world.commands().entity(self.entity).try_despawn();
+        }
+        runner_is
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once, wait};
+    use crate::prelude::{ActionFinished, Map, RunActionExt, Then};
+    use crate::testing;
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::{Commands, In, Local, On, ResMut, World};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn run_action_from_commands() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.run_action(
+                Update,
+                delay::frames()
+                    .with(1)
+                    .then(once::run(|mut count: ResMut<Count>| {
+                        count.increment();
+                    })),
+            );
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn trigger_finished_event() {
+        let mut app = test_app();
+        app.update();
+        let entity = app
+            .world_mut()
+            .run_action(Update, delay::frames().with(1).map(|_| 3_usize))
+            .observe(
+                |finished: On<ActionFinished<usize>>, mut count: ResMut<Count>| {
+                    count.0 = finished.output;
+                },
+            )
+            .id();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(3));
+        assert!(app.world().get_entity(entity).is_err());
+    }
+
+    #[test]
+    fn run_callback_system() {
+        let mut app = test_app();
+        app.update();
+        app.world_mut()
+            .run_system_once(|mut commands: Commands| {
+                commands.run_action_with(
+                    Update,
+                    wait::until(|mut frames: bevy::prelude::Local<usize>| {
+                        *frames += 1;
+                        *frames == 2
+                    })
+                    .map(|_| 10),
+                    |In(num): In<usize>, mut count: ResMut<Count>| {
+                        count.0 = num;
+                    },
+                );
+            })
+            .unwrap();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(10));
+    }
+
+    #[test]
+    fn cancel_by_despawning_entity() {
+        let mut app = test_app();
+        app.update();
+        let entity = app
+            .world_mut()
+            .run_action(
+                Update,
+                delay::frames()
+                    .with(1)
+                    .then(once::run(|mut count: ResMut<Count>| {
+                        count.increment();
+                    })),
+            )
+            .id();
+        app.update();
+        app.world_mut().despawn(entity);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+    }
+
+    #[test]
+    fn run_action_from_world_in_target_schedule() {
+        let mut app = test_app();
+        app.add_systems(Update, |world: &mut World, mut ran: Local<bool>| {
+            if core::mem::replace(&mut *ran, true) {
+                return;
+            }
+            world.run_action(Update, once::run(|| 3_usize)).observe(
+                |finished: On<ActionFinished<usize>>, mut count: ResMut<Count>| {
+                    count.0 = finished.output;
+                },
+            );
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn run_action_from_commands_in_target_schedule() {
+        let mut app = test_app();
+        app.add_systems(Update, |mut commands: Commands, mut ran: Local<bool>| {
+            if core::mem::replace(&mut *ran, true) {
+                return;
+            }
+            commands.run_action(Update, once::run(|| 3_usize)).observe(
+                |finished: On<ActionFinished<usize>>, mut count: ResMut<Count>| {
+                    count.0 = finished.output;
+                },
+            );
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn run_canceled_action_from_world_in_target_schedule() {
+        let mut app = test_app();
+        app.add_systems(Update, |world: &mut World, mut ran: Local<bool>| {
+            if !core::mem::replace(&mut *ran, true) {
+                world.run_action(Update, testing::cancel());
+            }
+        });
+        app.update();
+        app.update();
+    }
+
+    #[test]
+    fn despawn_canceled_action() {
+        let mut app = test_app();
+        app.update();
+        let entity = app.world_mut().run_action(Update, testing::cancel()).id();
+        app.update();
+        assert!(app.world().get_entity(entity).is_err());
+    }
+}
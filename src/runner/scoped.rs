@@ -0,0 +1,70 @@
+use crate::runner::{BoxedRunner, CancellationHandlers, CancellationId, Runner, RunnerIs};
+use alloc::sync::Arc;
+use bevy::platform::sync::Mutex;
+use bevy::prelude::World;
+
+/// A runner that registers its cancellation handlers in its own [`CancellationHandlers`].
+///
+/// The handlers are still called when the reactor is canceled,
+/// and can be called on their own with [`ScopedRunner::cancel`] when the runner is dropped before it completes,
+/// such as the losers of [`wait::any_output`](crate::prelude::wait::any_output).
+pub(crate) struct ScopedRunner {
+    runner: BoxedRunner,
+    handlers: Arc<Mutex<Option<CancellationHandlers>>>,
+    proxy: Option<CancellationId>,
+}
+
+impl ScopedRunner {
+    #[inline]
+    pub(crate) fn new(runner: BoxedRunner) -> Self {
+        Self {
+            runner,
+            handlers: Arc::new(Mutex::new(None)),
+            proxy: None,
+        }
+    }
+
+    /// Calls the cancellation handlers registered by the runner, and drops the runner.
+    pub(crate) fn cancel(mut self, world: &mut World, token: &mut CancellationHandlers) {
+        if let Some(proxy) = self.proxy.take() {
+            token.unregister(&proxy);
+        }
+        let handlers = self.handlers.lock().ok().and_then(|mut h| h.take());
+        drop(self.runner);
+        if let Some(mut handlers) = handlers {
+            handlers.cancel(world);
+        }
+    }
+
+    fn register_proxy(&mut self, token: &mut CancellationHandlers) {
+        let handlers = self.handlers.clone();
+        self.proxy.replace(token.register(move |world| {
+            let handlers = handlers.lock().ok().and_then(|mut h| h.take());
+            if let Some(mut handlers) = handlers {
+                handlers.cancel(world);
+            }
+        }));
+    }
+}
+
+impl Runner for ScopedRunner {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let Ok(mut guard) = self.handlers.lock() else {
+            return self.runner.run(world, token);
+        };
+        let handlers = guard.get_or_insert_with(|| token.scope());
+        let runner_is = self.runner.run(world, handlers);
+        let registered = !handlers.0.is_empty();
+        drop(guard);
+        match runner_is {
+            RunnerIs::Completed => {
+                if let Some(proxy) = self.proxy.take() {
+                    token.unregister(&proxy);
+                }
+            }
+            _ if registered && self.proxy.is_none() => self.register_proxy(token),
+            _ => {}
+        }
+        runner_is
+    }
+}
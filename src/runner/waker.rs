@@ -0,0 +1,301 @@
+use crate::runner::parallel::ParallelRunners;
+use crate::runner::BoxedRunner;
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use alloc::vec::Vec;
+use bevy::ecs::component::Tick;
+use bevy::ecs::message::MessageCursor;
+use bevy::platform::sync::Mutex;
+use bevy::prelude::{Component, Entity, Message, Messages, Resource, World};
+use core::marker::PhantomData;
+use core::task::Waker;
+
+/// Wakes the runners of a [`Reactor`](crate::prelude::Reactor) that returned [`RunnerIs::Sleeping`](crate::prelude::RunnerIs::Sleeping).
+///
+/// This can be obtained from [`CancellationHandlers::waker`](crate::prelude::CancellationHandlers::waker),
+/// and can be sent to any thread.
+/// Calling [`RunnerWaker::wake`] reschedules the sleeping runners on the next run of their schedule.
+///
+/// It can also be converted into [`Waker`] to wake the runners from a future,
+/// or registered to be woken by a message with [`RunnerWaker::wake_on_message`],
+/// or by a change of a resource or component with [`RunnerWaker::wake_on_resource_change`]
+/// and [`RunnerWaker::wake_on_component_change`].
+#[derive(Clone, Debug)]
+pub struct RunnerWaker {
+    reactor: Entity,
+    queue: WakeQueue,
+    parallel_runners: ParallelRunners,
+    detach: fn(&mut World, Entity, BoxedRunner),
+}
+
+impl RunnerWaker {
+    #[inline]
+    pub(crate) const fn new(
+        reactor: Entity,
+        queue: WakeQueue,
+        parallel_runners: ParallelRunners,
+        detach: fn(&mut World, Entity, BoxedRunner),
+    ) -> Self {
+        Self {
+            reactor,
+            queue,
+            parallel_runners,
+            detach,
+        }
+    }
+
+    /// Returns the runners evaluated in parallel in the schedule of the reactor.
+    #[inline]
+    pub(crate) const fn parallel_runners(&self) -> &ParallelRunners {
+        &self.parallel_runners
+    }
+
+    /// Returns the entity of the reactor to be woken.
+    #[inline]
+    pub const fn reactor(&self) -> Entity {
+        self.reactor
+    }
+
+    /// Detaches `runner` from the runner currently running on the reactor.
+    ///
+    /// The runner keeps running in the same schedule until it completes or the reactor is despawned,
+    /// but its completion doesn't step the reactor.
+    #[inline]
+    pub(crate) fn detach(&self, world: &mut World, runner: BoxedRunner) {
+        (self.detach)(world, self.reactor, runner);
+    }
+
+    /// Wakes the sleeping runners.
+    ///
+    /// Waking runners that are not sleeping has no effect.
+    #[inline]
+    pub fn wake(&self) {
+        self.queue.push(self.reactor);
+    }
+
+    /// Registers this waker to be woken when the message `M` is written.
+    ///
+    /// The waker is woken only once; register it again if the runner sleeps again.
+    pub fn wake_on_message<M: Message>(&self, world: &mut World) {
+        if !world.contains_resource::<MessageWakers<M>>() {
+            world.insert_resource(MessageWakers::<M>(Mutex::new(MessageWakersState {
+                wakers: Vec::new(),
+                cursor: MessageCursor::default(),
+            })));
+            WakeInterests::register(world, wake_message_wakers::<M>);
+        }
+        let current = world
+            .get_resource::<Messages<M>>()
+            .map(Messages::get_cursor_current);
+        let Ok(mut message_wakers) = world.resource::<MessageWakers<M>>().0.lock() else {
+            return;
+        };
+        if message_wakers.wakers.is_empty() {
+            if let Some(current) = current {
+                message_wakers.cursor = current;
+            }
+        }
+        if !message_wakers.wakers.iter().any(|waker| self.same(waker)) {
+            message_wakers.wakers.push(self.clone());
+        }
+    }
+
+    /// Registers this waker to be woken when the resource `R` is changed, inserted, or removed.
+    ///
+    /// The changes made since the previous system run are also detected,
+    /// so the change made just before the runner sleeps is not missed.
+    /// The waker is woken only once; register it again if the runner sleeps again.
+    pub fn wake_on_resource_change<R: Resource>(&self, world: &mut World) {
+        if !world.contains_resource::<ResourceChangeWakers<R>>() {
+            world.insert_resource(ResourceChangeWakers::<R>(
+                Mutex::new(Vec::new()),
+                PhantomData,
+            ));
+            WakeInterests::register(world, wake_resource_change_wakers::<R>);
+        }
+        let last_run = previous_tick(world);
+        if let Ok(mut wakers) = world.resource::<ResourceChangeWakers<R>>().0.lock() {
+            wakers.push((self.clone(), last_run));
+        }
+    }
+
+    /// Registers this waker to be woken when the component `C` of `entity` is changed, inserted, or removed.
+    ///
+    /// The changes made since the previous system run are also detected,
+    /// so the change made just before the runner sleeps is not missed.
+    /// The waker is woken only once; register it again if the runner sleeps again.
+    pub fn wake_on_component_change<C: Component>(&self, world: &mut World, entity: Entity) {
+        if !world.contains_resource::<ComponentChangeWakers<C>>() {
+            world.insert_resource(ComponentChangeWakers::<C>(
+                Mutex::new(Vec::new()),
+                PhantomData,
+            ));
+            WakeInterests::register(world, wake_component_change_wakers::<C>);
+        }
+        let last_run = previous_tick(world);
+        if let Ok(mut wakers) = world.resource::<ComponentChangeWakers<C>>().0.lock() {
+            wakers.push((self.clone(), entity, last_run));
+        }
+    }
+
+    #[inline]
+    fn same(&self, other: &RunnerWaker) -> bool {
+        self.reactor == other.reactor && Arc::ptr_eq(&self.queue.0, &other.queue.0)
+    }
+}
+
+impl Wake for RunnerWaker {
+    #[inline]
+    fn wake(self: Arc<Self>) {
+        RunnerWaker::wake(&self);
+    }
+
+    #[inline]
+    fn wake_by_ref(self: &Arc<Self>) {
+        RunnerWaker::wake(self);
+    }
+}
+
+impl From<RunnerWaker> for Waker {
+    #[inline]
+    fn from(waker: RunnerWaker) -> Self {
+        Waker::from(Arc::new(waker))
+    }
+}
+
+/// The reactors woken since the last run of the runners.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct WakeQueue(Arc<Mutex<Vec<Entity>>>);
+
+impl WakeQueue {
+    fn push(&self, reactor: Entity) {
+        if let Ok(mut queue) = self.0.lock() {
+            queue.push(reactor);
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.lock().map(|queue| queue.is_empty()).unwrap_or(true)
+    }
+
+    pub(crate) fn drain(&self) -> Vec<Entity> {
+        self.0
+            .lock()
+            .map(|mut queue| core::mem::take(&mut *queue))
+            .unwrap_or_default()
+    }
+}
+
+/// The functions that wake the runners waiting for something to happen in the world.
+///
+/// They are called with a shared reference to the world before evaluating the parallel runners,
+/// so that the exclusive runners system can be skipped if nothing woke.
+#[derive(Resource, Default)]
+pub(crate) struct WakeInterests(Vec<fn(&World)>);
+
+impl WakeInterests {
+    /// Registers `wake` to be called before running the runners in every schedule.
+    pub(crate) fn register(world: &mut World, wake: fn(&World)) {
+        world.get_resource_or_init::<WakeInterests>().0.push(wake);
+    }
+
+    pub(crate) fn wake(world: &World) {
+        let Some(interests) = world.get_resource::<WakeInterests>() else {
+            return;
+        };
+        for wake in &interests.0 {
+            wake(world);
+        }
+    }
+}
+
+#[derive(Resource)]
+struct MessageWakers<M: Message>(Mutex<MessageWakersState<M>>);
+
+struct MessageWakersState<M: Message> {
+    wakers: Vec<RunnerWaker>,
+    cursor: MessageCursor<M>,
+}
+
+fn wake_message_wakers<M: Message>(world: &World) {
+    let Some(message_wakers) = world.get_resource::<MessageWakers<M>>() else {
+        return;
+    };
+    let Ok(mut message_wakers) = message_wakers.0.lock() else {
+        return;
+    };
+    if message_wakers.wakers.is_empty() {
+        return;
+    }
+    let Some(messages) = world.get_resource::<Messages<M>>() else {
+        return;
+    };
+    if message_wakers.cursor.is_empty(messages) {
+        return;
+    }
+    message_wakers.cursor.clear(messages);
+    for waker in message_wakers.wakers.drain(..) {
+        waker.wake();
+    }
+}
+
+#[derive(Resource)]
+struct ResourceChangeWakers<R>(Mutex<Vec<(RunnerWaker, Tick)>>, PhantomData<fn() -> R>);
+
+#[derive(Resource)]
+struct ComponentChangeWakers<C>(
+    Mutex<Vec<(RunnerWaker, Entity, Tick)>>,
+    PhantomData<fn() -> C>,
+);
+
+/// Returns the tick before the current one,
+/// so that the changes made in the current system run are detected as well.
+#[inline]
+fn previous_tick(world: &mut World) -> Tick {
+    Tick::new(world.change_tick().get().wrapping_sub(1))
+}
+
+fn wake_resource_change_wakers<R: Resource>(world: &World) {
+    let Some(wakers) = world.get_resource::<ResourceChangeWakers<R>>() else {
+        return;
+    };
+    let Ok(mut wakers) = wakers.0.lock() else {
+        return;
+    };
+    if wakers.is_empty() {
+        return;
+    }
+    let this_run = world.read_change_tick();
+    let ticks = world.get_resource_change_ticks::<R>();
+    wakers.retain(|(waker, last_run)| {
+        if ticks.is_some_and(|ticks| !ticks.is_changed(*last_run, this_run)) {
+            return true;
+        }
+        waker.wake();
+        false
+    });
+}
+
+fn wake_component_change_wakers<C: Component>(world: &World) {
+    let Some(wakers) = world.get_resource::<ComponentChangeWakers<C>>() else {
+        return;
+    };
+    let Ok(mut wakers) = wakers.0.lock() else {
+        return;
+    };
+    if wakers.is_empty() {
+        return;
+    }
+    let this_run = world.read_change_tick();
+    wakers.retain(|(waker, entity, last_run)| {
+        let ticks = world
+            .get_entity(*entity)
+            .ok()
+            .and_then(|entity| entity.get_change_ticks::<C>());
+        if ticks.is_some_and(|ticks| !ticks.is_changed(*last_run, this_run)) {
+            return true;
+        }
+        waker.wake();
+        false
+    });
+}
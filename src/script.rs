@@ -0,0 +1,276 @@
+//! Provides [`ScriptReactor`], a reactor whose state can be saved and restored.
+//!
+//! The future of [`Reactor`] can't be serialized,
+//! so [`ScriptReactor`] describes the process as a list of serializable steps and a program counter instead.
+//! Each step is converted into an action and run through the same runners as [`Reactor`].
+//!
+//! With the `asset` feature, the actions can also be described in [`ActionScript`](asset::ActionScript) assets.
+
+use crate::action::once;
+use crate::prelude::{ActionSeed, Reactor, Then};
+use alloc::vec::Vec;
+use bevy::ecs::component::{Mutable, StorageType};
+use bevy::ecs::lifecycle::{ComponentHook, HookContext};
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::ecs::world::DeferredWorld;
+use bevy::prelude::{Component, Entity, Query};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "asset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "asset")))]
+pub mod asset;
+
+/// A step of [`ScriptReactor`].
+///
+/// This is usually implemented for an enum of the actions used in the scripts.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Clone)]
+/// enum CutsceneStep {
+///     Say(String),
+///     Wait(usize),
+/// }
+///
+/// impl ScriptStep for CutsceneStep {
+///     type Schedule = Update;
+///
+///     fn action(&self) -> ActionSeed {
+///         match self.clone() {
+///             Self::Say(line) => once::run(move || info!("{line}")),
+///             Self::Wait(frames) => delay::frames().with(frames).omit(),
+///         }
+///     }
+/// }
+/// ```
+pub trait ScriptStep: Serialize + DeserializeOwned + Clone + Send + Sync + 'static {
+    /// The schedule in which the actions of the steps are run.
+    type Schedule: ScheduleLabel + Default + Clone;
+
+    /// Creates the action of this step.
+    fn action(&self) -> ActionSeed;
+}
+
+/// A reactor that runs [`ScriptStep`]s in order, and whose state can be serialized.
+///
+/// When this component is added, a [`Reactor`] that runs the steps from the current program counter
+/// is scheduled on the same entity, and the entity is despawned after all steps have completed.
+/// The program counter is advanced in the same frame that a step completes,
+/// so the component can be saved at any time, and spawning the deserialized component resumes the script.
+/// If it is saved while a step is running, the step is restarted from the beginning when it is loaded.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Clone)]
+/// enum CutsceneStep {
+///     Wait(usize),
+/// }
+///
+/// impl ScriptStep for CutsceneStep {
+///     type Schedule = Update;
+///
+///     fn action(&self) -> ActionSeed {
+///         match self {
+///             Self::Wait(frames) => delay::frames().with(*frames).omit(),
+///         }
+///     }
+/// }
+///
+/// fn spawn_cutscene(mut commands: Commands) {
+///     commands.spawn(ScriptReactor::new(vec![
+///         CutsceneStep::Wait(30),
+///         CutsceneStep::Wait(60),
+///     ]));
+/// }
+///
+/// fn save(scripts: Query<&ScriptReactor<CutsceneStep>>) {
+///     for script in scripts.iter() {
+///         let _state = ron::to_string(script);
+///     }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash)]
+#[serde(bound = "")]
+pub struct ScriptReactor<S: ScriptStep> {
+    steps: Vec<S>,
+    pc: usize,
+}
+
+impl<S: ScriptStep> ScriptReactor<S> {
+    /// Creates a new [`ScriptReactor`] that runs the `steps` from the first one.
+    #[inline]
+    pub const fn new(steps: Vec<S>) -> Self {
+        Self { steps, pc: 0 }
+    }
+
+    /// Returns the steps of the script.
+    #[inline]
+    pub fn steps(&self) -> &[S] {
+        &self.steps
+    }
+
+    /// Returns the index of the step currently running.
+    ///
+    /// This is equal to the number of the steps if all steps have completed.
+    #[inline]
+    pub const fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Returns true if all steps have completed.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.steps.len() <= self.pc
+    }
+
+    fn current_step(&self) -> Option<S> {
+        self.steps.get(self.pc).cloned()
+    }
+}
+
+impl<S: ScriptStep> Component for ScriptReactor<S> {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+    type Mutability = Mutable;
+
+    fn on_add() -> Option<ComponentHook> {
+        Some(|mut world: DeferredWorld, context: HookContext| {
+            let entity = context.entity;
+            world
+                .commands()
+                .entity(entity)
+                .insert(Reactor::schedule(move |task| async move {
+                    while let Some(step) = task
+                        .will(S::Schedule::default(), current_step::<S>(entity))
+                        .await
+                    {
+                        task.will(
+                            S::Schedule::default(),
+                            step.action().then(advance::<S>(entity)),
+                        )
+                        .await;
+                    }
+                }));
+        })
+    }
+}
+
+fn current_step<S: ScriptStep>(entity: Entity) -> ActionSeed<(), Option<S>> {
+    once::run(move |scripts: Query<&ScriptReactor<S>>| {
+        scripts
+            .get(entity)
+            .ok()
+            .and_then(ScriptReactor::current_step)
+    })
+}
+
+fn advance<S: ScriptStep>(entity: Entity) -> ActionSeed {
+    once::run(move |mut scripts: Query<&mut ScriptReactor<S>>| {
+        if let Ok(mut script) = scripts.get_mut(entity) {
+            script.pc += 1;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once};
+    use crate::prelude::{ActionSeed, Omit, ScriptReactor, ScriptStep};
+    use crate::tests::test_app;
+    use alloc::vec;
+    use bevy::app::Update;
+    use bevy::prelude::{Entity, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Hash)]
+    enum TestStep {
+        Add(usize),
+        Wait(usize),
+    }
+
+    impl ScriptStep for TestStep {
+        type Schedule = Update;
+
+        fn action(&self) -> ActionSeed {
+            match *self {
+                Self::Add(num) => once::run(move |mut count: ResMut<Count>| {
+                    count.0 += num;
+                }),
+                Self::Wait(frames) => delay::frames().with(frames).omit(),
+            }
+        }
+    }
+
+    fn script() -> ScriptReactor<TestStep> {
+        ScriptReactor::new(vec![TestStep::Add(1), TestStep::Wait(2), TestStep::Add(10)])
+    }
+
+    #[test]
+    fn run_steps_in_order() {
+        let mut app = test_app();
+        app.update();
+        let entity = app.world_mut().spawn(script()).id();
+        app.update();
+        app.assert_resource_eq(Count(1));
+        assert_eq!(
+            app.world()
+                .get::<ScriptReactor<TestStep>>(entity)
+                .unwrap()
+                .pc(),
+            1
+        );
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(11));
+        app.update();
+        assert!(app.world().get_entity(entity).is_err());
+    }
+
+    #[test]
+    fn restore_from_saved_state() {
+        let mut app = test_app();
+        app.update();
+        let entity = app.world_mut().spawn(script()).id();
+        app.update();
+        let saved =
+            ron::to_string(app.world().get::<ScriptReactor<TestStep>>(entity).unwrap()).unwrap();
+        app.world_mut().despawn(entity);
+
+        let mut app = test_app();
+        app.update();
+        let loaded: ScriptReactor<TestStep> = ron::from_str(&saved).unwrap();
+        assert_eq!(loaded.pc(), 1);
+        app.world_mut().spawn(loaded);
+        // The `Wait(2)` step is restarted, and then `Add(10)` is run.
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(10));
+    }
+
+    #[test]
+    fn despawn_finished_script() {
+        let mut app = test_app();
+        app.update();
+        let entity: Entity = app
+            .world_mut()
+            .spawn(ScriptReactor::<TestStep>::new(vec![]))
+            .id();
+        app.update();
+        assert!(app.world().get_entity(entity).is_err());
+    }
+}
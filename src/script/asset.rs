@@ -0,0 +1,692 @@
+//! Provides [`ActionScript`], an asset describing the actions run by a [`Reactor`].
+//!
+//! Each action in the script is referenced by the name registered in [`ActionScriptRegistry`],
+//! and its input is written in RON, so cutscenes can be authored without recompiling.
+//!
+//! The scripts are loaded from the files with the `.flurx.ron` extension,
+//! and the reactors running a script are restarted when the script is modified.
+
+use crate::action::{delay, once, wait};
+use crate::prelude::{ActionSeed, Omit, Reactor, Then};
+use crate::reactor::NativeReactor;
+use crate::task::ReactorTask;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use bevy::app::{App, Plugin, PostUpdate, Update};
+use bevy::asset::io::Reader;
+use bevy::asset::{
+    Asset, AssetApp, AssetEvent, AssetEventSystems, AssetLoader, AssetServer, Assets, Handle,
+    LoadContext,
+};
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::input::keyboard::KeyCode;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::{
+    Commands, Component, Entity, IntoScheduleConfigs, Message, MessageReader, Query, Res, Resource,
+};
+use bevy::reflect::TypePath;
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::time::Duration;
+use ron::value::RawValue;
+use serde::de::{DeserializeOwned, Error as _};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Adds the [`ActionScript`] asset and its loader, and restarts the scripts when they are modified.
+///
+/// [`AssetPlugin`](bevy::asset::AssetPlugin) must be added before this plugin.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, FlurxPlugin, ActionScriptPlugin));
+/// ```
+pub struct ActionScriptPlugin;
+
+impl Plugin for ActionScriptPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<ActionScript>()
+            .init_asset_loader::<ActionScriptLoader>()
+            .init_resource::<ActionScriptRegistry>()
+            .add_message::<ActionScriptFailed>()
+            .add_systems(
+                PostUpdate,
+                restart_modified_scripts.after(AssetEventSystems),
+            );
+    }
+}
+
+/// The asset describing a tree of the actions registered in [`ActionScriptRegistry`].
+///
+/// The script is written in RON as an [`ActionScriptNode`].
+///
+/// ```ron
+/// Sequence([
+///     Action(name: "delay::time", input: 1.5),
+///     Any([
+///         Action(name: "wait::input::just_pressed", input: Space),
+///         Action(name: "delay::frames", input: 300),
+///     ]),
+///     Action(name: "dialogue", input: (text: "Hello")),
+/// ])
+/// ```
+#[derive(Asset, TypePath, Serialize, Deserialize, Debug, Clone)]
+#[serde(transparent)]
+pub struct ActionScript {
+    /// The root node of the script.
+    pub root: ActionScriptNode,
+}
+
+/// A node of [`ActionScript`].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum ActionScriptNode {
+    /// Runs the action registered with `name`.
+    Action {
+        /// The name registered in [`ActionScriptRegistry`].
+        name: String,
+
+        /// The input of the action written in RON.
+        ///
+        /// If omitted, the input is `()`.
+        #[serde(default = "unit_input")]
+        input: Box<RawValue>,
+    },
+
+    /// Runs the nodes in order.
+    Sequence(Vec<ActionScriptNode>),
+
+    /// Runs the nodes at the same time, and waits until all of them have completed.
+    All(Vec<ActionScriptNode>),
+
+    /// Runs the nodes at the same time, and waits until any of them has completed.
+    Any(Vec<ActionScriptNode>),
+}
+
+impl ActionScript {
+    /// Creates the action described by this script.
+    ///
+    /// Returns an error if the script contains an action that isn't registered,
+    /// or an input that can't be deserialized.
+    pub fn build(&self, registry: &ActionScriptRegistry) -> Result<ActionSeed, ActionScriptError> {
+        self.root.build(registry)
+    }
+}
+
+impl ActionScriptNode {
+    fn build(&self, registry: &ActionScriptRegistry) -> Result<ActionSeed, ActionScriptError> {
+        match self {
+            Self::Action { name, input } => registry.create(name, input),
+            Self::Sequence(nodes) => nodes.iter().try_fold(once::run(|| {}), |sequence, node| {
+                Ok(sequence.then(node.build(registry)?))
+            }),
+            Self::All(nodes) => Ok(wait::all().with(build_all(nodes, registry)?).omit()),
+            Self::Any(nodes) if nodes.is_empty() => Ok(once::run(|| {})),
+            Self::Any(nodes) => Ok(wait::any().with(build_all(nodes, registry)?).omit()),
+        }
+    }
+}
+
+fn build_all(
+    nodes: &[ActionScriptNode],
+    registry: &ActionScriptRegistry,
+) -> Result<Vec<ActionSeed>, ActionScriptError> {
+    nodes.iter().map(|node| node.build(registry)).collect()
+}
+
+fn unit_input() -> Box<RawValue> {
+    RawValue::from_boxed_ron("()".into()).expect("`()` is valid RON")
+}
+
+type ActionFactory =
+    Arc<dyn Fn(&RawValue) -> Result<ActionSeed, ActionScriptError> + Send + Sync + 'static>;
+
+/// The registry mapping the names used in [`ActionScript`] to the actions.
+///
+/// The following actions are registered by default.
+///
+/// | name                          | input                         |
+/// |-------------------------------|-------------------------------|
+/// | `delay::frames`               | the number of frames          |
+/// | `delay::time`                 | the seconds as a float        |
+/// | `wait::input::just_pressed`   | [`KeyCode`]                   |
+/// | `wait::input::just_released`  | [`KeyCode`]                   |
+/// | `wait::input::pressed`        | [`KeyCode`]                   |
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use serde::Deserialize;
+///
+/// #[derive(Message, Deserialize)]
+/// struct Dialogue {
+///     text: String,
+/// }
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, FlurxPlugin, ActionScriptPlugin))
+///     .add_message::<Dialogue>()
+///     .register_script_action("dialogue", once::message::write::<Dialogue>);
+/// ```
+#[derive(Resource, Clone)]
+pub struct ActionScriptRegistry(HashMap<String, ActionFactory>);
+
+impl ActionScriptRegistry {
+    /// Creates the registry without any actions.
+    #[inline]
+    pub fn empty() -> Self {
+        Self(HashMap::default())
+    }
+
+    /// Registers the action created by `seed` with `name`.
+    ///
+    /// The input written in the script is deserialized into `I`,
+    /// and the output of the action is ignored.
+    ///
+    /// If an action has already been registered with the same name, it is replaced.
+    pub fn register<I, O>(
+        &mut self,
+        name: impl Into<String>,
+        seed: impl Fn() -> ActionSeed<I, O> + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        I: DeserializeOwned + Send + Sync + 'static,
+        O: Send + Sync + 'static,
+    {
+        let name = name.into();
+        let action_name = name.clone();
+        self.0.insert(
+            name,
+            Arc::new(move |input: &RawValue| {
+                let input =
+                    input
+                        .into_rust::<I>()
+                        .map_err(|error| ActionScriptError::InvalidInput {
+                            name: action_name.clone(),
+                            message: error.to_string(),
+                        })?;
+                Ok(seed().with(input).omit())
+            }),
+        );
+        self
+    }
+
+    /// Returns true if an action has been registered with `name`.
+    #[inline]
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    fn create(&self, name: &str, input: &RawValue) -> Result<ActionSeed, ActionScriptError> {
+        let factory = self
+            .0
+            .get(name)
+            .ok_or_else(|| ActionScriptError::UnknownAction(name.to_string()))?;
+        factory(input)
+    }
+}
+
+impl Default for ActionScriptRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry
+            .register("delay::frames", delay::frames)
+            .register("delay::time", || {
+                ActionSeed::define(|Seconds(duration): Seconds| delay::time().with(duration))
+            })
+            .register(
+                "wait::input::just_pressed",
+                wait::input::just_pressed::<KeyCode>,
+            )
+            .register(
+                "wait::input::just_released",
+                wait::input::just_released::<KeyCode>,
+            )
+            .register("wait::input::pressed", wait::input::pressed::<KeyCode>);
+        registry
+    }
+}
+
+/// The input of `delay::time` in scripts, written as seconds.
+///
+/// It is validated when the script is built,
+/// so negative, NaN, or too large seconds are reported as [`ActionScriptError::InvalidInput`].
+struct Seconds(Duration);
+
+impl<'de> Deserialize<'de> for Seconds {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let seconds = f32::deserialize(deserializer)?;
+        Duration::try_from_secs_f32(seconds)
+            .map(Seconds)
+            .map_err(D::Error::custom)
+    }
+}
+
+impl Debug for ActionScriptRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+/// Registers the actions used in [`ActionScript`].
+pub trait ActionScriptAppExt {
+    /// Registers the action created by `seed` with `name`.
+    ///
+    /// See [`ActionScriptRegistry::register`].
+    fn register_script_action<I, O>(
+        &mut self,
+        name: impl Into<String>,
+        seed: impl Fn() -> ActionSeed<I, O> + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        I: DeserializeOwned + Send + Sync + 'static,
+        O: Send + Sync + 'static;
+}
+
+impl ActionScriptAppExt for App {
+    fn register_script_action<I, O>(
+        &mut self,
+        name: impl Into<String>,
+        seed: impl Fn() -> ActionSeed<I, O> + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        I: DeserializeOwned + Send + Sync + 'static,
+        O: Send + Sync + 'static,
+    {
+        self.world_mut()
+            .get_resource_or_init::<ActionScriptRegistry>()
+            .register(name, seed);
+        self
+    }
+}
+
+/// The error that occurs when an [`ActionScript`] can't be run.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ActionScriptError {
+    /// The script contains an action that isn't registered in [`ActionScriptRegistry`].
+    UnknownAction(String),
+
+    /// The input of the action couldn't be deserialized.
+    InvalidInput {
+        /// The name of the action.
+        name: String,
+
+        /// The message of the deserialization error.
+        message: String,
+    },
+
+    /// The script asset failed to load.
+    LoadFailed,
+}
+
+impl Display for ActionScriptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownAction(name) => write!(f, "the action `{name}` is not registered"),
+            Self::InvalidInput { name, message } => {
+                write!(f, "invalid input of the action `{name}`: {message}")
+            }
+            Self::LoadFailed => f.write_str("failed to load the action script"),
+        }
+    }
+}
+
+impl Error for ActionScriptError {}
+
+/// The message written when the [`ActionScript`] run by a reactor can't be built.
+///
+/// The reactor is despawned after this message is written.
+#[derive(Message, Debug, Clone, Eq, PartialEq)]
+pub struct ActionScriptFailed {
+    /// The entity of the reactor.
+    pub reactor: Entity,
+
+    /// The reason why the script couldn't be run.
+    pub error: ActionScriptError,
+}
+
+/// The component attached to the reactors created by [`Reactor::from_script`].
+///
+/// This is inserted when the reactor starts, and is used to restart the reactor when the script is modified.
+#[derive(Component)]
+pub struct RunningActionScript {
+    handle: Handle<ActionScript>,
+    restart: Arc<dyn Fn() -> ActionScriptReactor + Send + Sync>,
+}
+
+impl RunningActionScript {
+    /// Returns the handle of the script run by the reactor.
+    #[inline]
+    pub const fn handle(&self) -> &Handle<ActionScript> {
+        &self.handle
+    }
+}
+
+type ScriptFuture = Pin<Box<dyn Future<Output = ()> + Send + Sync>>;
+type ScriptFn = Box<dyn FnOnce(ReactorTask) -> ScriptFuture + Send + Sync>;
+type ActionScriptReactor = Reactor<ScriptFn, ScriptFuture>;
+
+impl Reactor<ScriptFn, ScriptFuture> {
+    /// Creates a [`Reactor`] that runs the [`ActionScript`] in [`Update`].
+    ///
+    /// The reactor waits until the script is loaded.
+    /// If the script is modified while running, the reactor is restarted from the beginning of the script.
+    ///
+    /// If the script can't be built, [`ActionScriptFailed`] is written and the reactor is despawned.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// fn spawn_cutscene(mut commands: Commands, asset_server: Res<AssetServer>) {
+    ///     commands.spawn(Reactor::from_script(asset_server.load("opening.flurx.ron")));
+    /// }
+    /// ```
+    #[inline]
+    pub fn from_script(handle: Handle<ActionScript>) -> Self {
+        Self::from_script_in(Update, handle)
+    }
+
+    /// Creates a [`Reactor`] that runs the [`ActionScript`] in `schedule`.
+    ///
+    /// See [`Reactor::from_script`].
+    pub fn from_script_in(
+        schedule: impl ScheduleLabel + Clone,
+        handle: Handle<ActionScript>,
+    ) -> Self {
+        Reactor::schedule(Box::new(move |task: ReactorTask| -> ScriptFuture {
+            Box::pin(async move {
+                let reactor = task.entity;
+                let script = task
+                    .will(
+                        schedule.clone(),
+                        start_script(reactor, schedule.clone(), handle.clone())
+                            .then(load_script(handle)),
+                    )
+                    .await;
+                match script {
+                    Ok(action) => task.will(schedule, action).await,
+                    Err(error) => {
+                        task.will(
+                            schedule,
+                            once::message::write().with(ActionScriptFailed { reactor, error }),
+                        )
+                        .await;
+                    }
+                }
+            })
+        }))
+    }
+}
+
+fn start_script(
+    reactor: Entity,
+    schedule: impl ScheduleLabel + Clone,
+    handle: Handle<ActionScript>,
+) -> ActionSeed {
+    once::run(move |mut commands: Commands| {
+        let schedule = schedule.clone();
+        let restart_handle = handle.clone();
+        commands.entity(reactor).insert(RunningActionScript {
+            handle: handle.clone(),
+            restart: Arc::new(move || {
+                Reactor::from_script_in(schedule.clone(), restart_handle.clone())
+            }),
+        });
+    })
+}
+
+fn load_script(
+    handle: Handle<ActionScript>,
+) -> ActionSeed<(), Result<ActionSeed, ActionScriptError>> {
+    wait::output(
+        move |scripts: Res<Assets<ActionScript>>,
+              registry: Res<ActionScriptRegistry>,
+              asset_server: Res<AssetServer>| {
+            if let Some(script) = scripts.get(&handle) {
+                return Some(script.build(&registry));
+            }
+            asset_server
+                .load_state(&handle)
+                .is_failed()
+                .then_some(Err(ActionScriptError::LoadFailed))
+        },
+    )
+}
+
+fn restart_modified_scripts(
+    mut commands: Commands,
+    mut events: MessageReader<AssetEvent<ActionScript>>,
+    running: Query<(Entity, &RunningActionScript)>,
+) {
+    for event in events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+        for (entity, script) in running.iter().filter(|(_, s)| s.handle.id() == *id) {
+            commands
+                .entity(entity)
+                .remove::<(ActionScriptReactor, NativeReactor)>()
+                .insert((script.restart)());
+        }
+    }
+}
+
+/// Loads [`ActionScript`] from the files with the `.flurx.ron` extension.
+#[derive(Default, TypePath)]
+pub struct ActionScriptLoader;
+
+/// The error that occurs when [`ActionScriptLoader`] fails to load a script.
+#[derive(Debug)]
+pub enum ActionScriptLoaderError {
+    /// Failed to read the file.
+    Io(std::io::Error),
+
+    /// The file isn't a valid script.
+    Ron(ron::error::SpannedError),
+}
+
+impl Display for ActionScriptLoaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read the action script: {error}"),
+            Self::Ron(error) => write!(f, "failed to parse the action script: {error}"),
+        }
+    }
+}
+
+impl Error for ActionScriptLoaderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Ron(error) => Some(error),
+        }
+    }
+}
+
+impl AssetLoader for ActionScriptLoader {
+    type Asset = ActionScript;
+    type Settings = ();
+    type Error = ActionScriptLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _: &Self::Settings,
+        _: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(ActionScriptLoaderError::Io)?;
+        ron::de::from_bytes(&bytes).map_err(ActionScriptLoaderError::Ron)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["flurx.ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{
+        ActionScript, ActionScriptAppExt, ActionScriptError, ActionScriptFailed,
+        ActionScriptPlugin, ActionScriptRegistry, ActionSeed, Reactor,
+    };
+    use crate::tests::test_app;
+    use bevy::app::{App, First};
+    use bevy::asset::{AssetPlugin, Assets, Handle};
+    use bevy::ecs::message::MessageCursor;
+    use bevy::input::keyboard::KeyCode;
+    use bevy::input::ButtonInput;
+    use bevy::prelude::{In, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    fn add() -> ActionSeed<usize> {
+        once::run(|In(num): In<usize>, mut count: ResMut<Count>| {
+            count.0 += num;
+        })
+    }
+
+    fn script_app() -> App {
+        let mut app = test_app();
+        app.add_plugins((AssetPlugin::default(), ActionScriptPlugin))
+            .register_script_action("add", add);
+        app.update();
+        app
+    }
+
+    fn add_script(app: &mut App, script: &str) -> Handle<ActionScript> {
+        let script: ActionScript = ron::from_str(script).unwrap();
+        app.world_mut()
+            .resource_mut::<Assets<ActionScript>>()
+            .add(script)
+    }
+
+    #[test]
+    fn run_sequence() {
+        let mut app = script_app();
+        let handle = add_script(
+            &mut app,
+            r#"Sequence([
+                Action(name: "add", input: 1),
+                Action(name: "delay::frames", input: 1),
+                Action(name: "add", input: 10),
+            ])"#,
+        );
+        let reactor = app.world_mut().spawn(Reactor::from_script(handle)).id();
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(11));
+        app.update();
+        assert!(app.world().get_entity(reactor).is_err());
+    }
+
+    #[test]
+    fn deserialize_enum_input() {
+        let mut app = script_app();
+        let handle = add_script(
+            &mut app,
+            r#"Sequence([
+                Any([
+                    Action(name: "wait::input::just_pressed", input: KeyA),
+                    Action(name: "delay::frames", input: 100),
+                ]),
+                Action(name: "add", input: 1),
+            ])"#,
+        );
+        app.world_mut()
+            .spawn(Reactor::from_script_in(First, handle));
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyA);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn restart_modified_script() {
+        let mut app = script_app();
+        let handle = add_script(
+            &mut app,
+            r#"Sequence([
+                Action(name: "delay::frames", input: 3),
+                Action(name: "add", input: 1),
+            ])"#,
+        );
+        app.world_mut().spawn(Reactor::from_script(handle.clone()));
+        app.update();
+        app.update();
+        let patched: ActionScript = ron::from_str(r#"Action(name: "add", input: 10)"#).unwrap();
+        *app.world_mut()
+            .resource_mut::<Assets<ActionScript>>()
+            .get_mut(&handle)
+            .unwrap() = patched;
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(10));
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(10));
+    }
+
+    #[test]
+    fn write_failed_message_if_action_is_unknown() {
+        let mut app = script_app();
+        let handle = add_script(&mut app, r#"Action(name: "unknown")"#);
+        let reactor = app.world_mut().spawn(Reactor::from_script(handle)).id();
+        app.update();
+        let mut cursor = MessageCursor::<ActionScriptFailed>::default();
+        let messages = app
+            .world()
+            .resource::<bevy::prelude::Messages<ActionScriptFailed>>();
+        assert_eq!(
+            cursor.read(messages).next(),
+            Some(&ActionScriptFailed {
+                reactor,
+                error: ActionScriptError::UnknownAction("unknown".into()),
+            })
+        );
+    }
+
+    #[test]
+    fn negative_seconds_are_invalid_input() {
+        let script: ActionScript =
+            ron::from_str(r#"Action(name: "delay::time", input: -1.0)"#).unwrap();
+        assert!(matches!(
+            script.build(&ActionScriptRegistry::default()),
+            Err(ActionScriptError::InvalidInput { .. })
+        ));
+    }
+
+    #[test]
+    fn invalid_input() {
+        let script: ActionScript =
+            ron::from_str(r#"Action(name: "delay::frames", input: "one")"#).unwrap();
+        assert!(matches!(
+            script.build(&ActionScriptRegistry::default()),
+            Err(ActionScriptError::InvalidInput { .. })
+        ));
+    }
+}
@@ -1,11 +1,12 @@
 //! Create a task that runs the system until certain conditions are met.
 
+use crate::action::wait::message::MessageSubscription;
 use crate::action::Action;
 use crate::core::task::CoreTask;
 use crate::selector::WorldSelector;
 use crate::world_ptr::WorldPtr;
 use bevy::ecs::schedule::ScheduleLabel;
-use bevy::prelude::Entity;
+use bevy::prelude::{Entity, Message};
 use core::future::Future;
 use futures_polling::FuturePollingExt;
 
@@ -95,6 +96,38 @@ impl ReactorTask {
         let _ = future.poll_once().await;
         future
     }
+
+    /// Subscribes to the message `M`.
+    ///
+    /// The returned [`MessageSubscription`] buffers the messages written after this call,
+    /// and they can be read one by one with [`wait::message::next`](crate::prelude::wait::message::next).
+    /// Unlike [`wait::message::read`](crate::prelude::wait::message::read), which starts reading when the action starts,
+    /// the messages written between actions are neither missed nor read twice.
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(Message, Clone)]
+    /// struct Hit;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let hits = task.subscribe::<Hit>();
+    ///     task.will(Update, delay::frames().with(60)).await;
+    ///     // Returns the hit even if it was written during the delay.
+    ///     task.will(Update, wait::message::next(&hits)).await;
+    /// });
+    /// ```
+    pub fn subscribe<M>(&self) -> MessageSubscription<M>
+    where
+        M: Message + Clone,
+    {
+        let world = self
+            .task
+            .state
+            .expect("The reactor task must be used inside the reactor.");
+        MessageSubscription::new(world.as_mut())
+    }
 }
 
 #[cfg(test)]
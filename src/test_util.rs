@@ -24,18 +24,31 @@ impl SpawnReactor for App {
 
 pub mod test {
     use bevy::prelude::World;
+    use bevy_test_helper::resource::count::Count;
 
     use crate::prelude::{ActionSeed, CancellationHandlers, Runner};
 
-    pub fn cancel() -> ActionSeed {
-        ActionSeed::new(|_, _| TestCancelRunner)
+    /// Never completes, and increments [`Count`] when it's canceled.
+    #[allow(unused)]
+    pub fn count_on_cancel() -> ActionSeed {
+        ActionSeed::new(|_, _| CountOnCancelRunner(false))
     }
 
-    struct TestCancelRunner;
+    struct CountOnCancelRunner(bool);
 
-    impl Runner for TestCancelRunner {
-        fn run(&mut self, _: &mut World, _: &mut CancellationHandlers) -> crate::prelude::RunnerIs {
-            crate::prelude::RunnerIs::Canceled
+    impl Runner for CountOnCancelRunner {
+        fn run(
+            &mut self,
+            _: &mut World,
+            token: &mut CancellationHandlers,
+        ) -> crate::prelude::RunnerIs {
+            if !self.0 {
+                self.0 = true;
+                token.register(|world| {
+                    world.resource_mut::<Count>().increment();
+                });
+            }
+            crate::prelude::RunnerIs::Running
         }
     }
 }
@@ -0,0 +1,566 @@
+//! Provides utilities for testing reactors and actions.
+//!
+//! [`FlurxTestApp`] is a headless app whose clock is advanced by a fixed duration each frame,
+//! so the reactors run in the same way every time the test is run.
+//!
+//! ```
+//! use bevy::prelude::*;
+//! use bevy_flurx::prelude::*;
+//! use bevy_flurx::testing::FlurxTestApp;
+//! use core::time::Duration;
+//!
+//! let mut app = FlurxTestApp::new();
+//! let output = app.spawn_action(Update, delay::time().with(Duration::from_secs(1)).map(|_| 3));
+//! output.assert_pending();
+//! app.advance_time(Duration::from_secs(1));
+//! output.assert_eq(3);
+//! ```
+
+use crate::prelude::{ActionSeed, CancellationHandlers, Reactor, Runner, RunnerIs};
+use crate::task::ReactorTask;
+use crate::testing::timeline::{apply_timeline, ActiveTimeline};
+use crate::FlurxPlugin;
+use alloc::sync::Arc;
+use bevy::app::{App, Plugins, PreUpdate};
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::input::{InputPlugin, InputSystems};
+use bevy::platform::sync::Mutex;
+use bevy::prelude::{resource_exists, Entity, IntoScheduleConfigs, World};
+use bevy::time::{Time, TimeUpdateStrategy, Virtual};
+use bevy::MinimalPlugins;
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter};
+use core::future::Future;
+use core::ops::{Deref, DerefMut};
+use core::time::Duration;
+
+pub use timeline::{InputTimeline, StepReport, TimelineAt, TimelineReport};
+
+mod timeline;
+
+/// The builder of [`FlurxTestApp`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_flurx::testing::FlurxTestApp;
+/// use core::time::Duration;
+///
+/// let app = FlurxTestApp::builder()
+///     .frame_time(Duration::from_millis(10))
+///     .add_plugins(|app: &mut App| {
+///         app.init_resource::<Time<Fixed>>();
+///     })
+///     .build();
+/// ```
+pub struct FlurxTestAppBuilder {
+    app: App,
+    frame_time: Duration,
+}
+
+impl FlurxTestAppBuilder {
+    /// Sets the duration the clock advances each frame.
+    ///
+    /// Defaults to `1 / 60` seconds.
+    #[inline]
+    pub fn frame_time(mut self, frame_time: Duration) -> Self {
+        self.frame_time = frame_time;
+        self
+    }
+
+    /// Adds the plugins to the app.
+    #[inline]
+    pub fn add_plugins<M>(mut self, plugins: impl Plugins<M>) -> Self {
+        self.app.add_plugins(plugins);
+        self
+    }
+
+    /// Builds the app, and runs the first frame.
+    ///
+    /// The clock doesn't advance in the first frame, so it's run here
+    /// to make the elapsed time equal to the frame time multiplied by the frames stepped in the test.
+    pub fn build(self) -> FlurxTestApp {
+        let mut app = self.app;
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(self.frame_time));
+        app.finish();
+        app.cleanup();
+        app.update();
+        FlurxTestApp {
+            app,
+            frame_time: self.frame_time,
+        }
+    }
+}
+
+/// A headless app for testing reactors.
+///
+/// It contains [`MinimalPlugins`], [`InputPlugin`], and [`FlurxPlugin`].
+/// The `StatesPlugin` is also added if the `state` feature is enabled.
+///
+/// The clock is advanced by the frame time each frame instead of the real time,
+/// and [`FlurxTestApp::advance_time`] runs a frame advancing the clock by the given duration.
+///
+/// This dereferences to [`App`].
+pub struct FlurxTestApp {
+    app: App,
+    frame_time: Duration,
+}
+
+impl FlurxTestApp {
+    /// Creates the app with the default settings.
+    ///
+    /// See [`FlurxTestAppBuilder::build`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::builder().build()
+    }
+
+    /// Returns the builder of the app.
+    pub fn builder() -> FlurxTestAppBuilder {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin, FlurxPlugin))
+            .add_systems(
+                PreUpdate,
+                apply_timeline
+                    .after(InputSystems)
+                    .run_if(resource_exists::<ActiveTimeline>),
+            );
+        #[cfg(feature = "state")]
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        FlurxTestAppBuilder {
+            app,
+            frame_time: Duration::from_secs(1) / 60,
+        }
+    }
+
+    /// Returns the duration the clock advances each frame.
+    #[inline]
+    pub const fn frame_time(&self) -> Duration {
+        self.frame_time
+    }
+
+    /// Runs `frames` frames.
+    pub fn step_frames(&mut self, frames: usize) -> &mut Self {
+        for _ in 0..frames {
+            self.app.update();
+        }
+        self
+    }
+
+    /// Runs a frame advancing the clock by `duration` instead of the frame time.
+    ///
+    /// The maximum delta of [`Time<Virtual>`] is raised during the frame if `duration` exceeds it,
+    /// and restored after the frame.
+    pub fn advance_time(&mut self, duration: Duration) -> &mut Self {
+        let mut time = self.app.world_mut().resource_mut::<Time<Virtual>>();
+        let max_delta = time.max_delta();
+        if max_delta < duration {
+            time.set_max_delta(duration);
+        }
+        self.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(duration));
+        self.app.update();
+        self.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(self.frame_time));
+        self.app
+            .world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .set_max_delta(max_delta);
+        self
+    }
+
+    /// Spawns the [`Reactor`], and returns its entity.
+    ///
+    /// The reactor starts immediately, so the runners registered in its first step run in the next frame.
+    pub fn spawn_reactor<F, Fut>(&mut self, f: F) -> Entity
+    where
+        F: FnOnce(ReactorTask) -> Fut + Send + Sync + 'static,
+        Fut: Future + Send + Sync + 'static,
+    {
+        self.app.world_mut().spawn(Reactor::schedule(f)).id()
+    }
+
+    /// Spawns a reactor running `action` in `schedule`, and returns [`TestOutput`] which receives its output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    /// use bevy_flurx::testing::FlurxTestApp;
+    ///
+    /// let mut app = FlurxTestApp::new();
+    /// let output = app.spawn_action(Update, delay::frames().with(1).map(|_| "done"));
+    /// app.step_frames(1);
+    /// output.assert_pending();
+    /// app.step_frames(1);
+    /// output.assert_eq("done");
+    /// ```
+    pub fn spawn_action<I, O>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        action: impl Into<crate::prelude::Action<I, O>> + Send + Sync + 'static,
+    ) -> TestOutput<O>
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+    {
+        let output = TestOutput::default();
+        let slot = output.clone();
+        self.spawn_reactor(|task| async move {
+            let out = task.will(schedule, action).await;
+            slot.set(out);
+        });
+        output
+    }
+
+    /// Runs frames until the reactor is despawned, and returns the number of the frames.
+    ///
+    /// Returns [`ReactorNotFinished`] if the reactor is still running after `max_frames` frames.
+    pub fn run_until_reactor_finished(
+        &mut self,
+        reactor: Entity,
+        max_frames: usize,
+    ) -> Result<usize, ReactorNotFinished> {
+        for frames in 0..=max_frames {
+            if !is_alive(self.app.world(), reactor) {
+                return Ok(frames);
+            }
+            if frames < max_frames {
+                self.app.update();
+            }
+        }
+        Err(ReactorNotFinished {
+            reactor,
+            frames: max_frames,
+        })
+    }
+
+    /// Runs frames applying the steps of `timeline` until the reactor is despawned,
+    /// and returns the results of the steps.
+    ///
+    /// At most `max_frames` frames are run.
+    /// The steps that are not due by then are reported as not applied.
+    pub fn run_timeline(
+        &mut self,
+        reactor: Entity,
+        timeline: InputTimeline,
+        max_frames: usize,
+    ) -> TimelineReport {
+        self.app.insert_resource(ActiveTimeline::new(timeline));
+        let mut frames = 0;
+        while frames < max_frames && is_alive(self.app.world(), reactor) {
+            self.app.update();
+            frames += 1;
+        }
+        let steps = self
+            .app
+            .world_mut()
+            .remove_resource::<ActiveTimeline>()
+            .map(ActiveTimeline::into_reports)
+            .unwrap_or_default();
+        TimelineReport {
+            steps,
+            frames,
+            finished_after: (!is_alive(self.app.world(), reactor)).then_some(frames),
+        }
+    }
+}
+
+impl Default for FlurxTestApp {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for FlurxTestApp {
+    type Target = App;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.app
+    }
+}
+
+impl DerefMut for FlurxTestApp {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.app
+    }
+}
+
+#[inline]
+fn is_alive(world: &World, reactor: Entity) -> bool {
+    world.get_entity(reactor).is_ok()
+}
+
+/// The error returned from [`FlurxTestApp::run_until_reactor_finished`]
+/// if the reactor is still running after the maximum frames.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ReactorNotFinished {
+    /// The entity of the reactor.
+    pub reactor: Entity,
+
+    /// The number of the frames run.
+    pub frames: usize,
+}
+
+impl Display for ReactorNotFinished {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "the reactor {} didn't finish within {} frames",
+            self.reactor, self.frames
+        )
+    }
+}
+
+impl Error for ReactorNotFinished {}
+
+/// Receives the output of the action spawned by [`FlurxTestApp::spawn_action`].
+pub struct TestOutput<O>(Arc<Mutex<Option<O>>>);
+
+impl<O> TestOutput<O> {
+    /// Returns true if the action has completed.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.0.lock().is_ok_and(|out| out.is_some())
+    }
+
+    /// Takes the output out.
+    ///
+    /// Returns [`None`] if the action hasn't completed or the output has already been taken.
+    #[inline]
+    pub fn take(&self) -> Option<O> {
+        self.0.lock().ok().and_then(|mut out| out.take())
+    }
+
+    /// Panics if the action has completed.
+    #[track_caller]
+    pub fn assert_pending(&self) {
+        assert!(!self.is_finished(), "the action has already completed");
+    }
+
+    fn set(&self, out: O) {
+        if let Ok(mut slot) = self.0.lock() {
+            slot.replace(out);
+        }
+    }
+}
+
+impl<O: Clone> TestOutput<O> {
+    /// Returns a clone of the output.
+    ///
+    /// Returns [`None`] if the action hasn't completed.
+    #[inline]
+    pub fn get(&self) -> Option<O> {
+        self.0.lock().ok().and_then(|out| out.clone())
+    }
+}
+
+impl<O: PartialEq + Debug> TestOutput<O> {
+    /// Panics if the action hasn't completed or its output isn't equal to `expected`.
+    #[track_caller]
+    pub fn assert_eq(&self, expected: O) {
+        let out = self.0.lock().unwrap();
+        match out.as_ref() {
+            Some(out) => assert_eq!(out, &expected),
+            None => panic!("the action hasn't completed; expected output: {expected:?}"),
+        }
+    }
+}
+
+impl<O> Clone for TestOutput<O> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<O> Default for TestOutput<O> {
+    #[inline]
+    fn default() -> Self {
+        Self(Arc::new(Mutex::new(None)))
+    }
+}
+
+impl<O: Debug> Debug for TestOutput<O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("TestOutput").field(&self.0).finish()
+    }
+}
+
+/// Creates an action that cancels the reactor.
+///
+/// This is useful for testing the behavior when a reactor is canceled,
+/// such as the cancellation handlers of custom runners.
+pub fn cancel() -> ActionSeed {
+    ActionSeed::new(|_, _| CancelRunner)
+}
+
+struct CancelRunner;
+
+impl Runner for CancelRunner {
+    fn run(&mut self, _: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+        RunnerIs::Canceled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once, wait};
+    use crate::prelude::{Map, Then};
+    use crate::testing::{cancel, FlurxTestApp, InputTimeline, ReactorNotFinished, TimelineAt};
+    use bevy::app::Update;
+    use bevy::input::keyboard::KeyCode;
+    use bevy::input::ButtonInput;
+    use bevy::prelude::Message;
+    use bevy::time::{Time, Virtual};
+    use core::time::Duration;
+
+    #[test]
+    fn step_frames() {
+        let mut app = FlurxTestApp::new();
+        let output = app.spawn_action(Update, delay::frames().with(2).map(|_| 1));
+        app.step_frames(2);
+        output.assert_pending();
+        app.step_frames(1);
+        output.assert_eq(1);
+    }
+
+    #[test]
+    fn advance_time() {
+        let mut app = FlurxTestApp::new();
+        let output = app.spawn_action(Update, delay::time().with(Duration::from_secs(10)));
+        app.advance_time(Duration::from_secs(9));
+        output.assert_pending();
+        app.advance_time(Duration::from_secs(1));
+        assert!(output.is_finished());
+    }
+
+    #[test]
+    fn restore_max_delta_after_advance_time() {
+        let mut app = FlurxTestApp::new();
+        let max_delta = app.world().resource::<Time<Virtual>>().max_delta();
+        app.advance_time(max_delta * 2);
+        assert_eq!(
+            app.world().resource::<Time<Virtual>>().max_delta(),
+            max_delta
+        );
+    }
+
+    #[test]
+    fn advance_time_by_frame_time() {
+        let mut app = FlurxTestApp::builder()
+            .frame_time(Duration::from_millis(250))
+            .build();
+        let output = app.spawn_action(Update, delay::time().with(Duration::from_secs(1)));
+        app.step_frames(3);
+        output.assert_pending();
+        app.step_frames(1);
+        assert!(output.is_finished());
+    }
+
+    #[test]
+    fn run_until_reactor_finished() {
+        let mut app = FlurxTestApp::new();
+        let reactor = app.spawn_reactor(|task| async move {
+            task.will(Update, delay::frames().with(5)).await;
+        });
+        assert_eq!(app.run_until_reactor_finished(reactor, 10), Ok(6));
+    }
+
+    #[test]
+    fn reactor_not_finished() {
+        let mut app = FlurxTestApp::new();
+        let reactor = app.spawn_reactor(|task| async move {
+            task.will(Update, wait::input::pressed().with(KeyCode::KeyA))
+                .await;
+        });
+        assert_eq!(
+            app.run_until_reactor_finished(reactor, 3),
+            Err(ReactorNotFinished { reactor, frames: 3 })
+        );
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyA);
+        assert!(app.run_until_reactor_finished(reactor, 3).is_ok());
+    }
+
+    #[test]
+    fn canceled_action_has_no_output() {
+        let mut app = FlurxTestApp::new();
+        let output = app.spawn_action(Update, cancel().then(once::run(|| 1)));
+        app.step_frames(3);
+        output.assert_pending();
+        assert_eq!(output.take(), None);
+    }
+
+    #[derive(Message, Debug, Clone, Eq, PartialEq)]
+    struct Skip;
+
+    #[test]
+    fn run_timeline() {
+        let mut app = FlurxTestApp::builder()
+            .frame_time(Duration::from_millis(10))
+            .build();
+        let reactor = app.spawn_reactor(|task| async move {
+            task.will(
+                Update,
+                wait::input::just_pressed()
+                    .with(KeyCode::KeyA)
+                    .then(wait::input::just_released().with(KeyCode::KeyA)),
+            )
+            .await;
+        });
+        let report = app.run_timeline(
+            reactor,
+            InputTimeline::new()
+                .press_at_frame(3, KeyCode::KeyA)
+                .release_at(Duration::from_millis(500), KeyCode::KeyA)
+                .press_at_frame(1000, KeyCode::KeyB),
+            100,
+        );
+        assert_eq!(report.steps[0].frame, Some(3));
+        assert_eq!(report.steps[1].frame, Some(50));
+        assert_eq!(report.steps[1].elapsed, Some(Duration::from_millis(500)));
+        assert!(!report.steps[2].applied());
+        assert_eq!(report.finished_after, Some(51));
+        assert!(!report.all_applied());
+    }
+
+    #[test]
+    fn write_message_in_timeline() {
+        let mut app = FlurxTestApp::new();
+        app.add_message::<Skip>();
+        let reactor = app.spawn_reactor(|task| async move {
+            task.will(Update, wait::message::comes::<Skip>()).await;
+        });
+        let report = app.run_timeline(
+            reactor,
+            InputTimeline::new().write_message(TimelineAt::Frame(2), Skip),
+            10,
+        );
+        assert!(report.all_applied());
+        assert_eq!(report.steps[0].label, "write Skip");
+        assert_eq!(report.finished_after, Some(3));
+    }
+
+    #[test]
+    fn report_unfinished_reactor() {
+        let mut app = FlurxTestApp::new();
+        let reactor = app.spawn_reactor(|task| async move {
+            task.will(Update, wait::input::just_pressed().with(KeyCode::KeyA))
+                .await;
+        });
+        let report = app.run_timeline(reactor, InputTimeline::new(), 5);
+        assert_eq!(report.frames, 5);
+        assert!(!report.finished());
+        assert_eq!(
+            alloc::string::ToString::to_string(&report),
+            "the reactor didn't finish within 5 frames"
+        );
+    }
+}
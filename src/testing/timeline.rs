@@ -0,0 +1,295 @@
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use bevy::input::ButtonInput;
+use bevy::prelude::{Message, Resource, Time, World};
+use bevy::utils::prelude::ShortName;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::Hash;
+use core::time::Duration;
+
+/// When the step of [`InputTimeline`] is applied.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TimelineAt {
+    /// The frame counted from `0`, which is the first frame run by the timeline.
+    Frame(usize),
+
+    /// The elapsed time since the timeline started.
+    ///
+    /// The step is applied in the first frame in which the clock reaches the time.
+    Time(Duration),
+}
+
+impl Display for TimelineAt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Frame(frame) => write!(f, "frame {frame}"),
+            Self::Time(time) => write!(f, "{time:?}"),
+        }
+    }
+}
+
+/// A timeline of the inputs and messages fed to the app while a reactor runs.
+///
+/// The steps are applied in [`PreUpdate`](bevy::app::PreUpdate) after [`InputSystems`](bevy::input::InputSystems),
+/// so `just_pressed` and `just_released` can be observed by the actions in the same frame.
+/// Steps due in the same frame are applied in the order they were added.
+///
+/// Run it with [`FlurxTestApp::run_timeline`](crate::testing::FlurxTestApp::run_timeline).
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use bevy_flurx::testing::{FlurxTestApp, InputTimeline};
+/// use core::time::Duration;
+///
+/// let mut app = FlurxTestApp::new();
+/// let reactor = app.spawn_reactor(|task| async move {
+///     task.will(Update, wait::input::just_pressed().with(KeyCode::KeyA)
+///         .then(wait::input::just_released().with(KeyCode::KeyA))
+///     ).await;
+/// });
+/// let report = app.run_timeline(
+///     reactor,
+///     InputTimeline::new()
+///         .press_at_frame(3, KeyCode::KeyA)
+///         .release_at(Duration::from_secs_f32(1.5), KeyCode::KeyA),
+///     300,
+/// );
+/// assert!(report.finished());
+/// assert!(report.all_applied());
+/// ```
+#[derive(Default)]
+pub struct InputTimeline {
+    steps: Vec<TimelineStep>,
+}
+
+struct TimelineStep {
+    at: TimelineAt,
+    label: String,
+    apply: Box<dyn FnOnce(&mut World) + Send + Sync>,
+}
+
+impl InputTimeline {
+    /// Creates an empty timeline.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the step that runs `f` at `at`.
+    ///
+    /// `label` is used to identify the step in [`TimelineReport`].
+    pub fn run(
+        mut self,
+        at: TimelineAt,
+        label: impl Into<String>,
+        f: impl FnOnce(&mut World) + Send + Sync + 'static,
+    ) -> Self {
+        self.steps.push(TimelineStep {
+            at,
+            label: label.into(),
+            apply: Box::new(f),
+        });
+        self
+    }
+
+    /// Presses `input` of [`ButtonInput<T>`] at `frame`.
+    #[inline]
+    pub fn press_at_frame<T>(self, frame: usize, input: T) -> Self
+    where
+        T: Copy + Eq + Hash + Debug + Send + Sync + 'static,
+    {
+        self.press(TimelineAt::Frame(frame), input)
+    }
+
+    /// Presses `input` of [`ButtonInput<T>`] when the elapsed time reaches `time`.
+    #[inline]
+    pub fn press_at<T>(self, time: Duration, input: T) -> Self
+    where
+        T: Copy + Eq + Hash + Debug + Send + Sync + 'static,
+    {
+        self.press(TimelineAt::Time(time), input)
+    }
+
+    /// Releases `input` of [`ButtonInput<T>`] at `frame`.
+    #[inline]
+    pub fn release_at_frame<T>(self, frame: usize, input: T) -> Self
+    where
+        T: Copy + Eq + Hash + Debug + Send + Sync + 'static,
+    {
+        self.release(TimelineAt::Frame(frame), input)
+    }
+
+    /// Releases `input` of [`ButtonInput<T>`] when the elapsed time reaches `time`.
+    #[inline]
+    pub fn release_at<T>(self, time: Duration, input: T) -> Self
+    where
+        T: Copy + Eq + Hash + Debug + Send + Sync + 'static,
+    {
+        self.release(TimelineAt::Time(time), input)
+    }
+
+    /// Writes `message` at `at`.
+    pub fn write_message<M: Message>(self, at: TimelineAt, message: M) -> Self {
+        self.run(
+            at,
+            format!("write {}", ShortName::of::<M>()),
+            move |world| {
+                world.write_message(message);
+            },
+        )
+    }
+
+    fn press<T>(self, at: TimelineAt, input: T) -> Self
+    where
+        T: Copy + Eq + Hash + Debug + Send + Sync + 'static,
+    {
+        self.run(at, format!("press {input:?}"), move |world| {
+            world.resource_mut::<ButtonInput<T>>().press(input);
+        })
+    }
+
+    fn release<T>(self, at: TimelineAt, input: T) -> Self
+    where
+        T: Copy + Eq + Hash + Debug + Send + Sync + 'static,
+    {
+        self.run(at, format!("release {input:?}"), move |world| {
+            world.resource_mut::<ButtonInput<T>>().release(input);
+        })
+    }
+}
+
+/// The result of a step of [`InputTimeline`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct StepReport {
+    /// The label of the step.
+    pub label: String,
+
+    /// When the step was scheduled.
+    pub at: TimelineAt,
+
+    /// The frame in which the step was applied, or [`None`] if it wasn't applied.
+    pub frame: Option<usize>,
+
+    /// The elapsed time since the timeline started when the step was applied.
+    pub elapsed: Option<Duration>,
+}
+
+impl StepReport {
+    /// Returns true if the step was applied.
+    #[inline]
+    pub const fn applied(&self) -> bool {
+        self.frame.is_some()
+    }
+}
+
+/// The result of [`FlurxTestApp::run_timeline`](crate::testing::FlurxTestApp::run_timeline).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TimelineReport {
+    /// The results of the steps in the order they were added.
+    pub steps: Vec<StepReport>,
+
+    /// The number of the frames run.
+    pub frames: usize,
+
+    /// The number of the frames run until the reactor finished, or [`None`] if it was still running.
+    pub finished_after: Option<usize>,
+}
+
+impl TimelineReport {
+    /// Returns true if the reactor finished.
+    #[inline]
+    pub const fn finished(&self) -> bool {
+        self.finished_after.is_some()
+    }
+
+    /// Returns true if all steps were applied.
+    #[inline]
+    pub fn all_applied(&self) -> bool {
+        self.steps.iter().all(StepReport::applied)
+    }
+}
+
+impl Display for TimelineReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for step in &self.steps {
+            match (step.frame, step.elapsed) {
+                (Some(frame), Some(elapsed)) => writeln!(
+                    f,
+                    "{} (at {}): applied at frame {frame} ({elapsed:?})",
+                    step.label, step.at
+                )?,
+                _ => writeln!(f, "{} (at {}): not applied", step.label, step.at)?,
+            }
+        }
+        match self.finished_after {
+            Some(frames) => write!(f, "the reactor finished after {frames} frames"),
+            None => write!(f, "the reactor didn't finish within {} frames", self.frames),
+        }
+    }
+}
+
+/// The timeline currently run by [`FlurxTestApp::run_timeline`](crate::testing::FlurxTestApp::run_timeline).
+#[derive(Resource)]
+pub(crate) struct ActiveTimeline {
+    pending: Vec<(usize, TimelineStep)>,
+    reports: Vec<StepReport>,
+    started_at: Option<Duration>,
+    frame: usize,
+}
+
+impl ActiveTimeline {
+    pub(crate) fn new(timeline: InputTimeline) -> Self {
+        let reports = timeline
+            .steps
+            .iter()
+            .map(|step| StepReport {
+                label: step.label.clone(),
+                at: step.at,
+                frame: None,
+                elapsed: None,
+            })
+            .collect();
+        Self {
+            pending: timeline.steps.into_iter().enumerate().collect(),
+            reports,
+            started_at: None,
+            frame: 0,
+        }
+    }
+
+    pub(crate) fn into_reports(self) -> Vec<StepReport> {
+        self.reports
+    }
+}
+
+pub(crate) fn apply_timeline(world: &mut World) {
+    let Some(mut timeline) = world.remove_resource::<ActiveTimeline>() else {
+        return;
+    };
+    let now = world.resource::<Time>().elapsed();
+    let started_at = *timeline.started_at.get_or_insert(now);
+    let elapsed = now.saturating_sub(started_at);
+    let frame = timeline.frame;
+    let (due, pending): (Vec<_>, Vec<_>) =
+        timeline
+            .pending
+            .into_iter()
+            .partition(|(_, step)| match step.at {
+                TimelineAt::Frame(at) => at <= frame,
+                TimelineAt::Time(at) => at <= elapsed,
+            });
+    timeline.pending = pending;
+    for (index, step) in due {
+        (step.apply)(world);
+        let report = &mut timeline.reports[index];
+        report.frame = Some(frame);
+        report.elapsed = Some(elapsed);
+    }
+    timeline.frame += 1;
+    world.insert_resource(timeline);
+}